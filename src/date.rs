@@ -0,0 +1,318 @@
+use std::{
+    convert::TryFrom,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Shows which parts of the [`FtpEntryDate`] were actually sent by the server.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FtpEntryDatePrecision {
+    /// Year, date and time of day are known.
+    Full,
+    /// The server sent the date without time of day, e.g. `Dec 21  2012`.
+    MissingTime,
+    /// The server sent the time of day without year, e.g. `Nov 24 10:13`.
+    MissingYear,
+    /// The server sent the time of day without year, and the year was
+    /// guessed by [`FtpEntryDate::infer_year`].
+    InferredYear,
+}
+
+/// Order of the day and month in numeric dates like `08-05-18`,
+/// which cannot be told apart by looking at a single line.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub enum FtpEntryDateOrder {
+    /// `MM-DD-YY`, the default of Windows servers.
+    #[default]
+    MonthDay,
+    /// `DD-MM-YY`, used by servers with European locales.
+    DayMonth,
+}
+
+/// Date of the entry as it was sent by the server.
+///
+/// Values are kept as is: `LIST` responses are in the server's local
+/// time and carry no information about the timezone, while `MLSD`
+/// and EPLF entries are always in UTC.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct FtpEntryDate {
+    year: Option<u16>,
+    month: u8,
+    day: u8,
+    hour: Option<u8>,
+    minute: Option<u8>,
+    second: Option<u8>,
+    precision: FtpEntryDatePrecision,
+}
+
+impl FtpEntryDate {
+    /// Returns a date without time of day, or `None` if the date is invalid.
+    /// ```rust
+    /// # use ftp_cmd_list_parse::{FtpEntryDate, FtpEntryDatePrecision};
+    /// let date = FtpEntryDate::from_ymd(2012, 12, 21).unwrap();
+    /// assert_eq!(date.precision(), FtpEntryDatePrecision::MissingTime);
+    /// assert!(FtpEntryDate::from_ymd(2013, 2, 29).is_none());
+    /// ```
+    pub fn from_ymd(year: u16, month: u8, day: u8) -> Option<Self> {
+        Self::checked(
+            Some(year),
+            month,
+            day,
+            None,
+            FtpEntryDatePrecision::MissingTime,
+        )
+    }
+
+    /// Returns a date with time of day, or `None` if the date or the time is invalid.
+    pub fn from_ymd_hm(year: u16, month: u8, day: u8, hour: u8, minute: u8) -> Option<Self> {
+        Self::checked(
+            Some(year),
+            month,
+            day,
+            Some((hour, minute)),
+            FtpEntryDatePrecision::Full,
+        )
+    }
+
+    /// Returns a date with time of day up to seconds, or `None` if the date or the time is invalid.
+    /// A leap second is allowed.
+    pub fn from_ymd_hms(
+        year: u16,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Option<Self> {
+        if second > 60 {
+            return None;
+        }
+
+        Self::from_ymd_hm(year, month, day, hour, minute).map(|date| Self {
+            second: Some(second),
+            ..date
+        })
+    }
+
+    /// Returns a UTC date by given number of seconds since 1970-01-01T00:00:00Z,
+    /// or `None` if the year does not fit into `u16`.
+    /// ```rust
+    /// # use ftp_cmd_list_parse::FtpEntryDate;
+    /// assert_eq!(
+    ///     FtpEntryDate::from_timestamp(825_718_503),
+    ///     FtpEntryDate::from_ymd_hms(1996, 3, 1, 22, 15, 3)
+    /// );
+    /// ```
+    pub fn from_timestamp(secs: i64) -> Option<Self> {
+        let (year, month, day) = civil_from_days(secs.div_euclid(86400));
+        let secs = secs.rem_euclid(86400);
+
+        Self::from_ymd_hms(
+            u16::try_from(year).ok()?,
+            month,
+            day,
+            (secs / 3600) as u8,
+            (secs % 3600 / 60) as u8,
+            (secs % 60) as u8,
+        )
+    }
+
+    /// Returns a date without year, or `None` if the date or the time is invalid.
+    pub fn from_md_hm(month: u8, day: u8, hour: u8, minute: u8) -> Option<Self> {
+        Self::checked(
+            None,
+            month,
+            day,
+            Some((hour, minute)),
+            FtpEntryDatePrecision::MissingYear,
+        )
+    }
+
+    fn checked(
+        year: Option<u16>,
+        month: u8,
+        day: u8,
+        time: Option<(u8, u8)>,
+        precision: FtpEntryDatePrecision,
+    ) -> Option<Self> {
+        if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) {
+            return None;
+        }
+
+        if let Some((hour, minute)) = time {
+            if hour > 23 || minute > 59 {
+                return None;
+            }
+        }
+
+        Some(Self {
+            year,
+            month,
+            day,
+            hour: time.map(|(hour, _)| hour),
+            minute: time.map(|(_, minute)| minute),
+            second: None,
+            precision,
+        })
+    }
+
+    /// Returns the year, if the server sent it.
+    pub fn year(&self) -> Option<u16> {
+        self.year
+    }
+
+    /// Returns the month starting from 1.
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Returns the day of the month starting from 1.
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Returns the hour, if the server sent time of day.
+    pub fn hour(&self) -> Option<u8> {
+        self.hour
+    }
+
+    /// Returns the minute, if the server sent time of day.
+    pub fn minute(&self) -> Option<u8> {
+        self.minute
+    }
+
+    /// Returns the second, if the server sent it.
+    pub fn second(&self) -> Option<u8> {
+        self.second
+    }
+
+    /// Shows which parts of the date were sent by the server.
+    pub fn precision(&self) -> FtpEntryDatePrecision {
+        self.precision
+    }
+
+    /// Guesses the year of a date that was sent without it.
+    ///
+    /// Unix servers omit the year for entries modified within the last six
+    /// months, so the result is the latest year that does not put the date
+    /// into the future relative to `now`. The timezone of the server is
+    /// unknown, so up to 28 hours in the future are tolerated.
+    /// Use [`infer_year_at_offset`](#method.infer_year_at_offset) if you know it.
+    ///
+    /// Dates that already have a year are returned as is.
+    /// ```rust
+    /// # use std::time::{Duration, UNIX_EPOCH};
+    /// # use ftp_cmd_list_parse::{FtpEntryDate, FtpEntryDatePrecision};
+    /// // 2021-01-02T12:00:00Z
+    /// let now = UNIX_EPOCH + Duration::from_secs(1_609_588_800);
+    ///
+    /// let date = FtpEntryDate::from_md_hm(12, 30, 10, 13).unwrap().infer_year(now);
+    /// assert_eq!(date.year(), Some(2020));
+    /// assert_eq!(date.precision(), FtpEntryDatePrecision::InferredYear);
+    ///
+    /// let date = FtpEntryDate::from_md_hm(1, 2, 10, 13).unwrap().infer_year(now);
+    /// assert_eq!(date.year(), Some(2021));
+    /// ```
+    pub fn infer_year(&self, now: SystemTime) -> Self {
+        self.infer(now, 0, 28 * 3600)
+    }
+
+    /// Guesses the year of a date that was sent without it, like
+    /// [`infer_year`](#method.infer_year) does, but with the known offset
+    /// of the server's timezone from UTC in seconds.
+    /// Only one hour of clock skew is tolerated.
+    pub fn infer_year_at_offset(&self, now: SystemTime, utc_offset: i32) -> Self {
+        self.infer(now, utc_offset, 3600)
+    }
+
+    fn infer(&self, now: SystemTime, utc_offset: i32, tolerance: i64) -> Self {
+        if self.year.is_some() {
+            return *self;
+        }
+
+        let now = match now.duration_since(UNIX_EPOCH) {
+            Ok(duration) => duration.as_secs() as i64,
+            Err(err) => -(err.duration().as_secs() as i64),
+        } + i64::from(utc_offset);
+
+        let seconds_of_day =
+            i64::from(self.hour.unwrap_or(0)) * 3600 + i64::from(self.minute.unwrap_or(0)) * 60;
+
+        let (year, _, _) = civil_from_days((now + tolerance).div_euclid(86400));
+        // Going back eight years is enough even for February 29.
+        for year in (year - 8..=year).rev() {
+            let fits = u16::try_from(year)
+                .ok()
+                .filter(|&year| self.day <= days_in_month(Some(year), self.month))
+                .filter(|_| {
+                    days_from_civil(year, self.month, self.day) * 86400 + seconds_of_day
+                        <= now + tolerance
+                });
+
+            if let Some(year) = fits {
+                return Self {
+                    year: Some(year),
+                    precision: FtpEntryDatePrecision::InferredYear,
+                    ..*self
+                };
+            }
+        }
+
+        *self
+    }
+}
+
+/// Converts an English abbreviated month name (`Jan`, `feb`, ...) to its number.
+pub(crate) fn month_from_abbr(value: &str) -> Option<u8> {
+    const MONTHS: [&str; 12] = [
+        "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+    ];
+
+    MONTHS
+        .iter()
+        .position(|month| month.eq_ignore_ascii_case(value))
+        .map(|idx| idx as u8 + 1)
+}
+
+/// Returns the number of days since 1970-01-01 for the given date of
+/// the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: u8, day: u8) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = i64::from(month);
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Returns year, month and day for the given number of days since 1970-01-01.
+fn civil_from_days(days: i64) -> (i64, u8, u8) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u8;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    (year, month, day)
+}
+
+fn is_leap_year(year: u16) -> bool {
+    (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400)
+}
+
+/// If the year is unknown, February is allowed to have 29 days.
+fn days_in_month(year: Option<u16>, month: u8) -> u8 {
+    match month {
+        2 if year.is_none_or(is_leap_year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
@@ -1,29 +1,158 @@
+mod as400;
+mod eplf;
+mod guardian;
+mod mlsd;
 mod msdos;
+mod mvs;
+mod netware;
 mod unix;
+mod vms;
 
 use std::{
     convert::{TryFrom, TryInto},
-    fmt::Display,
+    fmt::{self, Write},
     ops::Deref,
 };
 
-pub use msdos::FtpEntryMsdos;
-pub use unix::FtpEntryUnix;
+use ::regex::{Captures, Regex};
 
-/// Permissions of the Unix-like entry.
-#[non_exhaustive]
-#[derive(Debug)]
-pub struct FtpEntryPermissions(String);
+use crate::date::{month_from_abbr, FtpEntryDate, FtpEntryDateOrder};
+use crate::error::{FtpEntryError, FtpEntryField, FtpEntryFormat};
+use crate::permissions::FtpEntryPermissions;
+
+pub use as400::FtpEntryAs400;
+pub use eplf::FtpEntryEplf;
+pub use guardian::{FtpEntryGuardian, FtpEntryGuardianAccess, FtpEntryGuardianSecurity};
+pub use mlsd::{FtpEntryMlsd, FtpEntryMlsdPermissions, FtpEntryMlsdType};
+pub use msdos::{FtpEntryMsdos, FtpEntryMsdosRef};
+pub use mvs::{FtpEntryMvsDataset, FtpEntryMvsMember};
+pub use netware::{FtpEntryNetware, FtpEntryNetwareRights};
+pub(crate) use unix::FtpEntryUnixColumns;
+pub use unix::{FtpEntryUnix, FtpEntryUnixDevice, FtpEntryUnixRef};
+pub use vms::{FtpEntryVms, FtpEntryVmsAccess, FtpEntryVmsProtection, FtpEntryVmsUic};
+
+/// Pattern of the `Nov 24 10:13` or `Dec 21  2012` timestamp
+/// shared by Unix-like listings, see [`parse_month_date`].
+const MONTH_DATE: &str = r"
+    \s+(?P<timestamp>((?P<month1>\w{3})\s+
+        (?P<date1>\d{1,2})\s+
+        (?P<hour>\d{1,2}):(?P<minute>\d{2}))|
+        ((?P<month2>\w{3})\s+
+            (?P<date2>\d{1,2})\s+
+            (?P<year>\d{4})))";
+
+/// Joins patterns of the fields, every one is compiled in the verbose mode.
+fn pattern(fields: &[(FtpEntryField, &str)]) -> String {
+    fields
+        .iter()
+        .map(|(_, re)| format!("(?x:{})", re))
+        .collect()
+}
+
+/// Builds a regex of the whole line from patterns of its fields.
+fn whole(fields: &[(FtpEntryField, &str)]) -> Regex {
+    Regex::new(&pattern(fields)).unwrap()
+}
+
+/// Builds regexes of growing prefixes of the line:
+/// the first field, the first two fields and so on.
+/// A prefix must end where the whitespace before the next field begins.
+fn prefixes(fields: &[(FtpEntryField, &str)]) -> Vec<(FtpEntryField, Regex)> {
+    (1..=fields.len())
+        .map(|len| {
+            let mut prefix = pattern(&fields[..len]);
+            if fields
+                .get(len)
+                .is_some_and(|(_, re)| re.trim_start().starts_with(r"\s"))
+            {
+                prefix.push_str(r"(?:\s|$)");
+            }
+            (fields[len - 1].0, Regex::new(&prefix).unwrap())
+        })
+        .collect()
+}
+
+/// Finds the first field of the line that does not match its pattern.
+fn diagnose(
+    value: &str,
+    format: FtpEntryFormat,
+    prefixes: &[(FtpEntryField, Regex)],
+) -> FtpEntryError {
+    let mut offset = 0;
+
+    for (field, re) in prefixes {
+        match re.find(value) {
+            Some(m) => offset = m.end(),
+            None => {
+                // Point at the field itself rather than the whitespace before it.
+                let rest = &value[offset..];
+                let offset = offset + rest.len() - rest.trim_start().len();
+                return FtpEntryError::new(Some(format), *field, offset);
+            }
+        }
+    }
+
+    FtpEntryError::new(Some(format), FtpEntryField::Name, offset)
+}
+
+/// Parses either `Nov 24 10:13` or `Dec 21  2012` form of the timestamp
+/// captured by [`MONTH_DATE`].
+fn parse_month_date(caps: &Captures) -> Option<FtpEntryDate> {
+    let number = |s| caps.name(s).and_then(|v| v.as_str().parse::<u8>().ok());
+
+    if let Some(month) = caps.name("month1") {
+        FtpEntryDate::from_md_hm(
+            month_from_abbr(month.as_str())?,
+            number("date1")?,
+            number("hour")?,
+            number("minute")?,
+        )
+    } else {
+        FtpEntryDate::from_ymd(
+            caps.name("year")?.as_str().parse().ok()?,
+            month_from_abbr(caps.name("month2")?.as_str())?,
+            number("date2")?,
+        )
+    }
+}
+
+/// Normalized timestamp of a borrowed entry, e.g. `Dec 21 2012` or
+/// `2018-08-22T14:05`, kept inline like the permissions so that parsing
+/// does not allocate. Longer strings are rejected by [`fmt::Write`].
+#[derive(Debug, Copy, Clone, Default)]
+struct DateStr {
+    bytes: [u8; 16],
+    len: usize,
+}
 
-impl FtpEntryPermissions {
-    pub fn as_str(&self) -> &str {
-        &self.0
+impl DateStr {
+    /// Joins words of the value with single spaces.
+    fn collapse_whitespace(value: &str) -> Result<Self, fmt::Error> {
+        let mut date_str = Self::default();
+        for (idx, word) in value.split_whitespace().enumerate() {
+            if idx > 0 {
+                date_str.write_char(' ')?;
+            }
+            date_str.write_str(word)?;
+        }
+        Ok(date_str)
+    }
+
+    fn as_str(&self) -> &str {
+        // Only whole strings are ever written.
+        std::str::from_utf8(&self.bytes[..self.len]).unwrap()
     }
 }
 
-impl Display for FtpEntryPermissions {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", &self.0)
+impl fmt::Write for DateStr {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.bytes
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
     }
 }
 
@@ -57,18 +186,18 @@ impl From<char> for FtpEntryKind {
 }
 
 impl TryFrom<&str> for FtpEntryKind {
-    type Error = &'static str;
+    type Error = FtpEntryError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
         if value.len() == 1 {
-            Ok(value.chars().nth(0).unwrap().into())
+            Ok(value.chars().next().unwrap().into())
         } else {
-            Err("length of the value must be equal to 1")
+            Err(FtpEntryError::new(None, FtpEntryField::Kind, 0))
         }
     }
 }
 
-/// All fields that supports both servers: Unix & MSDOS
+/// All fields that supports every server: Unix, MSDOS and others
 pub trait FtpEntryInfo {
     /// Returns a new [`FtpEntry`] by given string if parsing was successful.
     /// Also you can create new [`FtpEntry`] by use [`TryFrom`] trait.
@@ -97,20 +226,35 @@ pub trait FtpEntryInfo {
     /// Returns name of the entry.
     fn name(&self) -> &str;
     /// Returns size of the entry.
-    fn size(&self) -> usize;
-    // fn date(&self) -> NaiveDateTime;
-    /// Returns date of the entry.
+    fn size(&self) -> u64;
+    /// Returns parsed date of the entry.
+    /// Returns `None` if the server did not send the date.
+    /// ```rust
+    /// # use ftp_cmd_list_parse::{FtpEntry, FtpEntryDate};
+    /// let ftp_entry = FtpEntry::new("drwxr-xr-x  10 root   root    4096 Dec 21  2012 usr").unwrap();
+    /// assert_eq!(ftp_entry.date(), FtpEntryDate::from_ymd(2012, 12, 21));
+    /// ```
+    fn date(&self) -> Option<FtpEntryDate>;
+    /// Returns date of the entry as a string.
     fn date_str(&self) -> &str;
 }
 
 /// Represents parsed string as ftp entry.
 ///
 /// Implements [`Deref`] to `&dyn FtpEntryInfo`, so you can get access
-/// to general fields that supports both servers: Unix & MSDOS.
+/// to general fields that supports every server: Unix, MSDOS and others.
 #[derive(Debug)]
 pub enum FtpEntry {
     Unix(FtpEntryUnix),
     Msdos(FtpEntryMsdos),
+    Mlsd(FtpEntryMlsd),
+    Eplf(FtpEntryEplf),
+    Vms(FtpEntryVms),
+    MvsDataset(FtpEntryMvsDataset),
+    MvsMember(FtpEntryMvsMember),
+    As400(FtpEntryAs400),
+    Guardian(FtpEntryGuardian),
+    Netware(FtpEntryNetware),
 }
 
 impl FtpEntry {
@@ -135,18 +279,52 @@ impl FtpEntry {
 
     /// Returns true if [`FtpEntry`] has UNIX-like entry, otherwise false.
     pub fn is_unix_type(&self) -> bool {
-        match self {
-            FtpEntry::Unix(_) => true,
-            _ => false,
-        }
+        matches!(self, FtpEntry::Unix(_))
     }
 
     /// Returns true if [`FtpEntry`] has MSDOS-like entry, otherwise false.
     pub fn is_msdos_type(&self) -> bool {
-        match self {
-            FtpEntry::Msdos(_) => true,
-            _ => false,
-        }
+        matches!(self, FtpEntry::Msdos(_))
+    }
+
+    /// Returns true if [`FtpEntry`] has MLSD entry, otherwise false.
+    pub fn is_mlsd_type(&self) -> bool {
+        matches!(self, FtpEntry::Mlsd(_))
+    }
+
+    /// Returns true if [`FtpEntry`] has EPLF entry, otherwise false.
+    pub fn is_eplf_type(&self) -> bool {
+        matches!(self, FtpEntry::Eplf(_))
+    }
+
+    /// Returns true if [`FtpEntry`] has a VMS entry, otherwise false.
+    pub fn is_vms_type(&self) -> bool {
+        matches!(self, FtpEntry::Vms(_))
+    }
+
+    /// Returns true if [`FtpEntry`] has a MVS dataset entry, otherwise false.
+    pub fn is_mvs_dataset_type(&self) -> bool {
+        matches!(self, FtpEntry::MvsDataset(_))
+    }
+
+    /// Returns true if [`FtpEntry`] has a MVS member entry, otherwise false.
+    pub fn is_mvs_member_type(&self) -> bool {
+        matches!(self, FtpEntry::MvsMember(_))
+    }
+
+    /// Returns true if [`FtpEntry`] has an AS/400 entry, otherwise false.
+    pub fn is_as400_type(&self) -> bool {
+        matches!(self, FtpEntry::As400(_))
+    }
+
+    /// Returns true if [`FtpEntry`] has a Guardian entry, otherwise false.
+    pub fn is_guardian_type(&self) -> bool {
+        matches!(self, FtpEntry::Guardian(_))
+    }
+
+    /// Returns true if [`FtpEntry`] has a NetWare entry, otherwise false.
+    pub fn is_netware_type(&self) -> bool {
+        matches!(self, FtpEntry::Netware(_))
     }
 
     /// Converts [`FtpEntry`] to [`FtpEntryUnix`].
@@ -171,8 +349,91 @@ impl FtpEntry {
         self.try_to_msdos_type().expect("FtpEntryType missmatch")
     }
 
+    /// Converts [`FtpEntry`] to [`FtpEntryMlsd`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is not a MLSD entry.
+    /// If you not sure what kind of [`FtpEntry`] is, use [`try_to_mlsd_type`](#method.try_to_mlsd_type) instead.
+    pub fn to_mlsd_type(self) -> FtpEntryMlsd {
+        self.try_to_mlsd_type().expect("FtpEntryType missmatch")
+    }
+
+    /// Converts [`FtpEntry`] to [`FtpEntryEplf`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is not an EPLF entry.
+    /// If you not sure what kind of [`FtpEntry`] is, use [`try_to_eplf_type`](#method.try_to_eplf_type) instead.
+    pub fn to_eplf_type(self) -> FtpEntryEplf {
+        self.try_to_eplf_type().expect("FtpEntryType missmatch")
+    }
+
+    /// Converts [`FtpEntry`] to [`FtpEntryVms`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is not a VMS entry.
+    /// If you not sure what kind of [`FtpEntry`] is, use [`try_to_vms_type`](#method.try_to_vms_type) instead.
+    pub fn to_vms_type(self) -> FtpEntryVms {
+        self.try_to_vms_type().expect("FtpEntryType missmatch")
+    }
+
+    /// Converts [`FtpEntry`] to [`FtpEntryMvsDataset`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is not a MVS dataset entry.
+    /// If you not sure what kind of [`FtpEntry`] is, use [`try_to_mvs_dataset_type`](#method.try_to_mvs_dataset_type) instead.
+    pub fn to_mvs_dataset_type(self) -> FtpEntryMvsDataset {
+        self.try_to_mvs_dataset_type()
+            .expect("FtpEntryType missmatch")
+    }
+
+    /// Converts [`FtpEntry`] to [`FtpEntryMvsMember`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is not a MVS member entry.
+    /// If you not sure what kind of [`FtpEntry`] is, use [`try_to_mvs_member_type`](#method.try_to_mvs_member_type) instead.
+    pub fn to_mvs_member_type(self) -> FtpEntryMvsMember {
+        self.try_to_mvs_member_type()
+            .expect("FtpEntryType missmatch")
+    }
+
+    /// Converts [`FtpEntry`] to [`FtpEntryAs400`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is not an AS/400 entry.
+    /// If you not sure what kind of [`FtpEntry`] is, use [`try_to_as400_type`](#method.try_to_as400_type) instead.
+    pub fn to_as400_type(self) -> FtpEntryAs400 {
+        self.try_to_as400_type().expect("FtpEntryType missmatch")
+    }
+
+    /// Converts [`FtpEntry`] to [`FtpEntryGuardian`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is not a Guardian entry.
+    /// If you not sure what kind of [`FtpEntry`] is, use [`try_to_guardian_type`](#method.try_to_guardian_type) instead.
+    pub fn to_guardian_type(self) -> FtpEntryGuardian {
+        self.try_to_guardian_type().expect("FtpEntryType missmatch")
+    }
+
+    /// Converts [`FtpEntry`] to [`FtpEntryNetware`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is not a NetWare entry.
+    /// If you not sure what kind of [`FtpEntry`] is, use [`try_to_netware_type`](#method.try_to_netware_type) instead.
+    pub fn to_netware_type(self) -> FtpEntryNetware {
+        self.try_to_netware_type().expect("FtpEntryType missmatch")
+    }
+
     /// Tries to convert [`FtpEntry`] to [`FtpEntryUnix`].
     /// If it is impossible, returns [`FtpEntry`] back to caller.
+    #[allow(clippy::result_large_err)]
     pub fn try_to_unix_type(self) -> Result<FtpEntryUnix, Self> {
         if let FtpEntry::Unix(entry) = self {
             Ok(entry)
@@ -183,6 +444,7 @@ impl FtpEntry {
 
     /// Tries to convert [`FtpEntry`] to [`FtpEntryMsdos`].
     /// If it is impossible, returns [`FtpEntry`] back to caller.
+    #[allow(clippy::result_large_err)]
     pub fn try_to_msdos_type(self) -> Result<FtpEntryMsdos, Self> {
         if let FtpEntry::Msdos(entry) = self {
             Ok(entry)
@@ -190,6 +452,94 @@ impl FtpEntry {
             Err(self)
         }
     }
+
+    /// Tries to convert [`FtpEntry`] to [`FtpEntryMlsd`].
+    /// If it is impossible, returns [`FtpEntry`] back to caller.
+    #[allow(clippy::result_large_err)]
+    pub fn try_to_mlsd_type(self) -> Result<FtpEntryMlsd, Self> {
+        if let FtpEntry::Mlsd(entry) = self {
+            Ok(entry)
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Tries to convert [`FtpEntry`] to [`FtpEntryEplf`].
+    /// If it is impossible, returns [`FtpEntry`] back to caller.
+    #[allow(clippy::result_large_err)]
+    pub fn try_to_eplf_type(self) -> Result<FtpEntryEplf, Self> {
+        if let FtpEntry::Eplf(entry) = self {
+            Ok(entry)
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Tries to convert [`FtpEntry`] to [`FtpEntryVms`].
+    /// If it is impossible, returns [`FtpEntry`] back to caller.
+    #[allow(clippy::result_large_err)]
+    pub fn try_to_vms_type(self) -> Result<FtpEntryVms, Self> {
+        if let FtpEntry::Vms(entry) = self {
+            Ok(entry)
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Tries to convert [`FtpEntry`] to [`FtpEntryMvsDataset`].
+    /// If it is impossible, returns [`FtpEntry`] back to caller.
+    #[allow(clippy::result_large_err)]
+    pub fn try_to_mvs_dataset_type(self) -> Result<FtpEntryMvsDataset, Self> {
+        if let FtpEntry::MvsDataset(entry) = self {
+            Ok(entry)
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Tries to convert [`FtpEntry`] to [`FtpEntryMvsMember`].
+    /// If it is impossible, returns [`FtpEntry`] back to caller.
+    #[allow(clippy::result_large_err)]
+    pub fn try_to_mvs_member_type(self) -> Result<FtpEntryMvsMember, Self> {
+        if let FtpEntry::MvsMember(entry) = self {
+            Ok(entry)
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Tries to convert [`FtpEntry`] to [`FtpEntryAs400`].
+    /// If it is impossible, returns [`FtpEntry`] back to caller.
+    #[allow(clippy::result_large_err)]
+    pub fn try_to_as400_type(self) -> Result<FtpEntryAs400, Self> {
+        if let FtpEntry::As400(entry) = self {
+            Ok(entry)
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Tries to convert [`FtpEntry`] to [`FtpEntryGuardian`].
+    /// If it is impossible, returns [`FtpEntry`] back to caller.
+    #[allow(clippy::result_large_err)]
+    pub fn try_to_guardian_type(self) -> Result<FtpEntryGuardian, Self> {
+        if let FtpEntry::Guardian(entry) = self {
+            Ok(entry)
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Tries to convert [`FtpEntry`] to [`FtpEntryNetware`].
+    /// If it is impossible, returns [`FtpEntry`] back to caller.
+    #[allow(clippy::result_large_err)]
+    pub fn try_to_netware_type(self) -> Result<FtpEntryNetware, Self> {
+        if let FtpEntry::Netware(entry) = self {
+            Ok(entry)
+        } else {
+            Err(self)
+        }
+    }
 }
 
 impl Deref for FtpEntry {
@@ -199,22 +549,165 @@ impl Deref for FtpEntry {
         match self {
             FtpEntry::Msdos(entry) => entry,
             FtpEntry::Unix(entry) => entry,
+            FtpEntry::Mlsd(entry) => entry,
+            FtpEntry::Eplf(entry) => entry,
+            FtpEntry::Vms(entry) => entry,
+            FtpEntry::MvsDataset(entry) => entry,
+            FtpEntry::MvsMember(entry) => entry,
+            FtpEntry::As400(entry) => entry,
+            FtpEntry::Guardian(entry) => entry,
+            FtpEntry::Netware(entry) => entry,
         }
     }
 }
 
 impl TryFrom<&str> for FtpEntry {
-    type Error = ();
+    type Error = FtpEntryError;
 
+    /// Tries every supported format in turn. If none of them fits,
+    /// returns the error of the format that got furthest into the string.
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        if let Ok(entry) = FtpEntryUnix::try_from(value) {
-            return Ok(FtpEntry::Unix(entry));
+        FtpEntry::parse_with_date_order(value, FtpEntryDateOrder::default())
+    }
+}
+
+impl FtpEntry {
+    /// Tries every supported format in turn like [`TryFrom`] does, reading
+    /// numeric dates of MSDOS-like entries in the given order.
+    pub fn parse_with_date_order(
+        value: &str,
+        order: FtpEntryDateOrder,
+    ) -> Result<Self, FtpEntryError> {
+        let errors = [
+            match FtpEntryUnix::try_from(value) {
+                Ok(entry) => return Ok(FtpEntry::Unix(entry)),
+                Err(err) => err,
+            },
+            match FtpEntryMsdos::parse_with_date_order(value, order) {
+                Ok(entry) => return Ok(FtpEntry::Msdos(entry)),
+                Err(err) => err,
+            },
+            match FtpEntryMlsd::try_from(value) {
+                Ok(entry) => return Ok(FtpEntry::Mlsd(entry)),
+                Err(err) => err,
+            },
+            match FtpEntryEplf::try_from(value) {
+                Ok(entry) => return Ok(FtpEntry::Eplf(entry)),
+                Err(err) => err,
+            },
+            match FtpEntryVms::try_from(value) {
+                Ok(entry) => return Ok(FtpEntry::Vms(entry)),
+                Err(err) => err,
+            },
+            match FtpEntryMvsDataset::try_from(value) {
+                Ok(entry) => return Ok(FtpEntry::MvsDataset(entry)),
+                Err(err) => err,
+            },
+            match FtpEntryMvsMember::try_from(value) {
+                Ok(entry) => return Ok(FtpEntry::MvsMember(entry)),
+                Err(err) => err,
+            },
+            match FtpEntryAs400::try_from(value) {
+                Ok(entry) => return Ok(FtpEntry::As400(entry)),
+                Err(err) => err,
+            },
+            match FtpEntryGuardian::try_from(value) {
+                Ok(entry) => return Ok(FtpEntry::Guardian(entry)),
+                Err(err) => err,
+            },
+            match FtpEntryNetware::try_from(value) {
+                Ok(entry) => return Ok(FtpEntry::Netware(entry)),
+                Err(err) => err,
+            },
+        ];
+
+        Err(errors
+            .iter()
+            .rev()
+            .max_by_key(|err| err.progress())
+            .cloned()
+            .unwrap())
+    }
+}
+
+/// Borrowed counterpart of [`FtpEntry`] for large listings: strings of the
+/// entry point into the parsed line instead of being copied.
+///
+/// Only Unix and MSDOS entries have borrowed types, use [`FtpEntry`]
+/// for other formats.
+/// ```rust
+/// # use std::convert::TryFrom;
+/// # use ftp_cmd_list_parse::{FtpEntry, FtpEntryRef};
+/// let line = "drwxr-xr-x  10 root   root    4096 Dec 21  2012 usr";
+/// let entry = FtpEntryRef::try_from(line).unwrap();
+/// assert_eq!(entry.name(), "usr");
+///
+/// let entry: FtpEntry = entry.to_owned();
+/// assert_eq!(entry.is_unix_type(), true);
+/// ```
+#[derive(Debug, Clone)]
+pub enum FtpEntryRef<'a> {
+    Unix(FtpEntryUnixRef<'a>),
+    Msdos(FtpEntryMsdosRef<'a>),
+}
+
+impl<'a> FtpEntryRef<'a> {
+    /// Returns a new [`FtpEntryRef`] by given string if parsing was successful.
+    pub fn new(string: &'a str) -> Option<Self> {
+        FtpEntryRef::try_from(string).ok()
+    }
+
+    /// Tries Unix and MSDOS formats like [`TryFrom`] does, reading
+    /// numeric dates of MSDOS-like entries in the given order.
+    pub fn parse_with_date_order(
+        value: &'a str,
+        order: FtpEntryDateOrder,
+    ) -> Result<Self, FtpEntryError> {
+        let errors = [
+            match FtpEntryUnixRef::try_from(value) {
+                Ok(entry) => return Ok(FtpEntryRef::Unix(entry)),
+                Err(err) => err,
+            },
+            match FtpEntryMsdosRef::parse_with_date_order(value, order) {
+                Ok(entry) => return Ok(FtpEntryRef::Msdos(entry)),
+                Err(err) => err,
+            },
+        ];
+
+        Err(errors
+            .iter()
+            .rev()
+            .max_by_key(|err| err.progress())
+            .cloned()
+            .unwrap())
+    }
+
+    /// Copies the borrowed strings into a new [`FtpEntry`].
+    pub fn to_owned(&self) -> FtpEntry {
+        match self {
+            FtpEntryRef::Unix(entry) => FtpEntry::Unix(entry.to_owned()),
+            FtpEntryRef::Msdos(entry) => FtpEntry::Msdos(entry.to_owned()),
         }
+    }
+}
+
+impl<'a> Deref for FtpEntryRef<'a> {
+    type Target = dyn FtpEntryInfo + 'a;
 
-        if let Ok(entry) = FtpEntryMsdos::try_from(value) {
-            return Ok(FtpEntry::Msdos(entry));
+    fn deref(&self) -> &Self::Target {
+        match self {
+            FtpEntryRef::Unix(entry) => entry,
+            FtpEntryRef::Msdos(entry) => entry,
         }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for FtpEntryRef<'a> {
+    type Error = FtpEntryError;
 
-        Err(())
+    /// Tries Unix and MSDOS formats in turn. If neither fits,
+    /// returns the error of the format that got furthest into the string.
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        FtpEntryRef::parse_with_date_order(value, FtpEntryDateOrder::default())
     }
 }
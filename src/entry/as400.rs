@@ -0,0 +1,170 @@
+use std::convert::TryFrom;
+
+use ::regex::{Captures, Regex};
+
+use super::*;
+
+/// Patterns of the fields in the order they appear in the line.
+const FIELDS: [(FtpEntryField, &str); 5] = [
+    (FtpEntryField::Owner, r"^(?P<owner>[^\s*]\S*)"),
+    (FtpEntryField::Size, r"\s+(?P<size>\d+)"),
+    (
+        FtpEntryField::Timestamp,
+        r"
+        \s+(?P<timestamp>(?P<month>\d{2})/(?P<day>\d{2})/(?P<year>\d{2,4})\s+
+            (?P<hour>\d{2}):(?P<minute>\d{2}):(?P<second>\d{2}))",
+    ),
+    (FtpEntryField::Kind, r"\s+(?P<type>\*[A-Z]+)"),
+    (FtpEntryField::Name, r"\s+(?P<name>.+?)\s*$"),
+];
+
+/// Patterns of the fields of a member line, which is indented
+/// to the column of the object type.
+const MEMBER_FIELDS: [(FtpEntryField, &str); 2] = [
+    (FtpEntryField::Kind, r"^\s+(?P<type>\*MEM)"),
+    (FtpEntryField::Name, r"\s+(?P<name>.+?)\s*$"),
+];
+
+lazy_static! {
+    static ref RELIST: Regex = whole(&FIELDS);
+    static ref REPREFIXES: Vec<(FtpEntryField, Regex)> = prefixes(&FIELDS);
+    static ref REMEMBER: Regex = whole(&MEMBER_FIELDS);
+    static ref REMEMBERPREFIXES: Vec<(FtpEntryField, Regex)> = prefixes(&MEMBER_FIELDS);
+}
+
+/// Represents entry from IBM AS/400 (OS/400, IBM i) FTP server:
+/// ```text
+/// QSYS           77824 02/23/00 15:09:55 *DIR       RCHFOLDER/
+/// QPGMR          12288 06/08/20 17:49:31 *FILE      QGPL/QCLSRC.FILE
+///                                        *MEM       QGPL/QCLSRC.FILE/START.MBR
+/// ```
+///
+/// Members of a file are sent without owner, size and date.
+#[derive(Debug)]
+pub struct FtpEntryAs400 {
+    kind: FtpEntryKind,
+    name: String,
+    size: u64,
+    date: Option<FtpEntryDate>,
+    date_str: String,
+    /// The user profile that owns the object, `None` for members.
+    pub owner: Option<String>,
+    /// Type of the object as sent by the server, e.g. `*STMF` or `*LIB`.
+    pub object_type: String,
+}
+
+impl FtpEntryAs400 {
+    /// Represents parsed string as entry of an AS/400 FTP server.
+    pub fn new(string: &str) -> Option<Self> {
+        FtpEntryAs400::try_from(string).ok()
+    }
+}
+
+impl FtpEntryInfo for FtpEntryAs400 {
+    fn kind(&self) -> FtpEntryKind {
+        self.kind
+    }
+
+    /// Returns name of the entry without the trailing `/` of directories.
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn size(&self) -> u64 {
+        self.size
+    }
+
+    fn date(&self) -> Option<FtpEntryDate> {
+        self.date
+    }
+
+    fn date_str(&self) -> &str {
+        &self.date_str
+    }
+}
+
+impl TryFrom<&str> for FtpEntryAs400 {
+    type Error = FtpEntryError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let error = |field, offset| {
+            FtpEntryError::new(Some(FtpEntryFormat::As400), field, offset)
+                .with_progress(value.len())
+        };
+
+        if let Some(caps) = RELIST.captures(value).or_else(|| REMEMBER.captures(value)) {
+            let object_type = caps["type"].to_string();
+            let name = &caps["name"];
+
+            let kind = match object_type.as_str() {
+                _ if name.ends_with('/') => FtpEntryKind::Directory,
+                "*DIR" | "*LIB" | "*FLR" => FtpEntryKind::Directory,
+                "*FILE" | "*MEM" | "*STMF" | "*DOC" => FtpEntryKind::File,
+                _ => FtpEntryKind::UNKNOWN,
+            };
+
+            let size = caps
+                .name("size")
+                .map(|v| {
+                    v.as_str()
+                        .parse()
+                        .map_err(|_| error(FtpEntryField::Size, v.start()))
+                })
+                .transpose()?
+                .unwrap_or_default();
+
+            let date = caps
+                .name("timestamp")
+                .map(|v| {
+                    parse_date(&caps).ok_or_else(|| error(FtpEntryField::Timestamp, v.start()))
+                })
+                .transpose()?;
+
+            return Ok(Self {
+                kind,
+                name: name.trim_end_matches('/').to_string(),
+                size,
+                date,
+                date_str: caps.name("timestamp").map_or_else(String::new, |v| {
+                    v.as_str().split_whitespace().collect::<Vec<_>>().join(" ")
+                }),
+                owner: caps.name("owner").map(|v| v.as_str().to_string()),
+                object_type,
+            });
+        }
+
+        if value.starts_with(char::is_whitespace) {
+            return Err(diagnose(value, FtpEntryFormat::As400, &REMEMBERPREFIXES));
+        }
+
+        // Any word and number look like the owner and the size, so they do not
+        // count as progress against other formats.
+        let err = diagnose(value, FtpEntryFormat::As400, &REPREFIXES);
+        Err(match err.field() {
+            FtpEntryField::Size | FtpEntryField::Timestamp => err.with_progress(0),
+            _ => err,
+        })
+    }
+}
+
+/// Parses `02/23/00 15:09:55` form of the timestamp.
+fn parse_date(caps: &Captures) -> Option<FtpEntryDate> {
+    let number = |s| caps.name(s).and_then(|v| v.as_str().parse::<u8>().ok());
+
+    let year = match caps.name("year")?.as_str() {
+        year if year.len() < 4 => {
+            let year: u16 = year.parse().ok()?;
+            year + if year < 70 { 2000 } else { 1900 }
+        }
+        year => year.parse().ok()?,
+    };
+
+    FtpEntryDate::from_ymd_hms(
+        year,
+        number("month")?,
+        number("day")?,
+        number("hour")?,
+        number("minute")?,
+        number("second")?,
+    )
+}
@@ -0,0 +1,139 @@
+use std::convert::TryFrom;
+
+use super::*;
+
+/// Represents entry in the Easily Parsed LIST Format, where the name
+/// follows a tab character:
+/// ```text
+/// +i8388621.48594,m825718503,r,s280,\tdjb.html
+/// +i8388621.50690,m824255907,/,\t514
+/// ```
+///
+/// Dates of the entry are always in UTC.
+#[derive(Debug)]
+pub struct FtpEntryEplf {
+    kind: FtpEntryKind,
+    name: String,
+    size: u64,
+    date: Option<FtpEntryDate>,
+    date_str: String,
+    /// The `/` fact: `CWD` to this entry may work.
+    pub cwd: bool,
+    /// The `r` fact: `RETR` of this entry may work.
+    pub retr: bool,
+    /// The `m` fact: modification time in seconds since the epoch.
+    pub mtime: Option<i64>,
+    /// The `i` fact, identifies the entry on the server.
+    pub unique: Option<String>,
+    /// The `up` fact: Unix permissions of the entry.
+    pub unix_mode: Option<u32>,
+}
+
+impl FtpEntryEplf {
+    /// Represents parsed string as entry in the EPLF.
+    pub fn new(string: &str) -> Option<Self> {
+        FtpEntryEplf::try_from(string).ok()
+    }
+}
+
+impl FtpEntryInfo for FtpEntryEplf {
+    fn kind(&self) -> FtpEntryKind {
+        self.kind
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn size(&self) -> u64 {
+        self.size
+    }
+
+    fn date(&self) -> Option<FtpEntryDate> {
+        self.date
+    }
+
+    fn date_str(&self) -> &str {
+        &self.date_str
+    }
+}
+
+impl TryFrom<&str> for FtpEntryEplf {
+    type Error = FtpEntryError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let error = |field, offset| FtpEntryError::new(Some(FtpEntryFormat::Eplf), field, offset);
+
+        if !value.starts_with('+') {
+            return Err(error(FtpEntryField::Fact, 0));
+        }
+
+        let tab = value
+            .find('\t')
+            .ok_or_else(|| error(FtpEntryField::Name, value.len()))?;
+        let name = &value[tab + 1..];
+        if name.is_empty() {
+            return Err(error(FtpEntryField::Name, value.len()));
+        }
+
+        let error = |field, offset| error(field, offset).with_progress(value.len());
+
+        let mut entry = Self {
+            kind: FtpEntryKind::UNKNOWN,
+            name: name.to_string(),
+            size: 0,
+            date: None,
+            date_str: String::new(),
+            cwd: false,
+            retr: false,
+            mtime: None,
+            unique: None,
+            unix_mode: None,
+        };
+
+        let mut offset = 1;
+        for fact in value[1..tab].split(',') {
+            let mut chars = fact.chars();
+            match chars.next() {
+                Some('/') => entry.cwd = true,
+                Some('r') => entry.retr = true,
+                Some('s') => {
+                    entry.size = chars
+                        .as_str()
+                        .parse()
+                        .map_err(|_| error(FtpEntryField::Size, offset))?;
+                }
+                Some('m') => {
+                    let mtime = chars.as_str();
+                    entry.mtime = Some(
+                        mtime
+                            .parse()
+                            .map_err(|_| error(FtpEntryField::Timestamp, offset))?,
+                    );
+                    entry.date = entry.mtime.and_then(FtpEntryDate::from_timestamp);
+                    entry.date_str = mtime.to_string();
+                }
+                Some('i') => entry.unique = Some(chars.as_str().to_string()),
+                Some('u') if chars.as_str().starts_with('p') => {
+                    entry.unix_mode = Some(
+                        u32::from_str_radix(&chars.as_str()[1..], 8)
+                            .map_err(|_| error(FtpEntryField::Permissions, offset))?,
+                    );
+                }
+                // Unknown facts must be ignored.
+                _ => {}
+            }
+            offset += fact.len() + 1;
+        }
+
+        entry.kind = if entry.cwd {
+            FtpEntryKind::Directory
+        } else if entry.retr {
+            FtpEntryKind::File
+        } else {
+            FtpEntryKind::UNKNOWN
+        };
+
+        Ok(entry)
+    }
+}
@@ -0,0 +1,217 @@
+use std::convert::TryFrom;
+
+use ::regex::{Captures, Regex};
+
+use super::*;
+
+/// Patterns of the fields in the order they appear in the line.
+const FIELDS: [(FtpEntryField, &str); 6] = [
+    (FtpEntryField::Name, r"^(?P<name>[A-Za-z][A-Za-z0-9]{0,7})"),
+    (FtpEntryField::Kind, r"(?:\s+(?P<code>\d+))?"),
+    (FtpEntryField::Size, r"\s+(?P<size>\d+)"),
+    (
+        FtpEntryField::Timestamp,
+        r"
+        \s+(?P<timestamp>(?P<day>\d{1,2})-(?P<month>[A-Za-z]{3})-(?P<year>\d{2})\s+
+            (?P<hour>\d{1,2}):(?P<minute>\d{2}):(?P<second>\d{2}))",
+    ),
+    (FtpEntryField::Owner, r"\s+(?P<group>\d+),\s*(?P<user>\d+)"),
+    (
+        FtpEntryField::Permissions,
+        r#"\s+"(?P<security>[OGANCUogancu-]{4})"\s*$"#,
+    ),
+];
+
+lazy_static! {
+    static ref RELIST: Regex = whole(&FIELDS);
+    static ref REPREFIXES: Vec<(FtpEntryField, Regex)> = prefixes(&FIELDS);
+    static ref REHEADER: Regex =
+        Regex::new(r"^File\s+(?:Code\s+)?EOF\s+Last\s+Modification\s+Owner\s+RWEP\s*$").unwrap();
+}
+
+/// Who is allowed one kind of access to the Guardian entry.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FtpEntryGuardianAccess {
+    /// `O`: the owner, local access only.
+    Owner,
+    /// `G`: the owner's group, local access only.
+    Group,
+    /// `A`: any local user.
+    Any,
+    /// `U`: the owner, local or remote.
+    NetworkOwner,
+    /// `C`: the owner's group, local or remote.
+    Community,
+    /// `N`: any local or remote user.
+    Network,
+    /// `-`: the local super ID only.
+    SuperId,
+}
+
+impl FtpEntryGuardianAccess {
+    fn from_char(value: char) -> Option<Self> {
+        match value.to_ascii_uppercase() {
+            'O' => Some(Self::Owner),
+            'G' => Some(Self::Group),
+            'A' => Some(Self::Any),
+            'U' => Some(Self::NetworkOwner),
+            'C' => Some(Self::Community),
+            'N' => Some(Self::Network),
+            '-' => Some(Self::SuperId),
+            _ => None,
+        }
+    }
+}
+
+/// Security string of the Guardian entry, e.g. `"nunu"`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FtpEntryGuardianSecurity {
+    pub read: FtpEntryGuardianAccess,
+    pub write: FtpEntryGuardianAccess,
+    pub execute: FtpEntryGuardianAccess,
+    pub purge: FtpEntryGuardianAccess,
+}
+
+/// Represents entry from Tandem / HP NonStop Guardian FTP server:
+/// ```text
+/// File         Code             EOF  Last Modification    Owner  RWEP
+/// EDITFILE       101         8192  12-Jul-99 11:47:36  255, 0 "oooo"
+/// ```
+#[derive(Debug)]
+pub struct FtpEntryGuardian {
+    name: String,
+    size: u64,
+    date: FtpEntryDate,
+    date_str: String,
+    /// The file code, e.g. `101` for edit files, if the server sent it.
+    pub file_code: Option<u16>,
+    /// Group ID of the owner.
+    pub group_id: u8,
+    /// User ID of the owner within the group.
+    pub user_id: u8,
+    /// The security string of the entry.
+    pub security: FtpEntryGuardianSecurity,
+}
+
+impl FtpEntryGuardian {
+    /// Represents parsed string as entry of a Guardian FTP server.
+    pub fn new(string: &str) -> Option<Self> {
+        FtpEntryGuardian::try_from(string).ok()
+    }
+
+    /// Returns true if the line is the header of the listing,
+    /// that names the columns.
+    pub fn is_header(line: &str) -> bool {
+        REHEADER.is_match(line)
+    }
+}
+
+impl FtpEntryInfo for FtpEntryGuardian {
+    /// Guardian servers list only files, so it is always [`FtpEntryKind::File`].
+    fn kind(&self) -> FtpEntryKind {
+        FtpEntryKind::File
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the end-of-file position of the entry in bytes.
+    fn size(&self) -> u64 {
+        self.size
+    }
+
+    fn date(&self) -> Option<FtpEntryDate> {
+        Some(self.date)
+    }
+
+    fn date_str(&self) -> &str {
+        &self.date_str
+    }
+}
+
+impl TryFrom<&str> for FtpEntryGuardian {
+    type Error = FtpEntryError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let error = |field, offset| {
+            FtpEntryError::new(Some(FtpEntryFormat::Guardian), field, offset)
+                .with_progress(value.len())
+        };
+
+        if let Some(caps) = RELIST.captures(value) {
+            let file_code = caps
+                .name("code")
+                .map(|_| number(&caps, "code"))
+                .transpose()
+                .map_err(|offset| error(FtpEntryField::Kind, offset))?;
+            let size =
+                number(&caps, "size").map_err(|offset| error(FtpEntryField::Size, offset))?;
+
+            let timestamp = caps.name("timestamp").unwrap();
+            let date = parse_date(&caps)
+                .ok_or_else(|| error(FtpEntryField::Timestamp, timestamp.start()))?;
+            let date_str = timestamp
+                .as_str()
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ");
+
+            let security = {
+                let mut classes = caps["security"]
+                    .chars()
+                    .map(|c| FtpEntryGuardianAccess::from_char(c).unwrap());
+                FtpEntryGuardianSecurity {
+                    read: classes.next().unwrap(),
+                    write: classes.next().unwrap(),
+                    execute: classes.next().unwrap(),
+                    purge: classes.next().unwrap(),
+                }
+            };
+
+            return Ok(Self {
+                name: caps["name"].to_string(),
+                size,
+                date,
+                date_str,
+                file_code,
+                group_id: number(&caps, "group")
+                    .map_err(|offset| error(FtpEntryField::Owner, offset))?,
+                user_id: number(&caps, "user")
+                    .map_err(|offset| error(FtpEntryField::Owner, offset))?,
+                security,
+            });
+        }
+
+        // Any word and numbers look like the name, the code and the size,
+        // so they do not count as progress against other formats.
+        let err = diagnose(value, FtpEntryFormat::Guardian, &REPREFIXES);
+        Err(match err.field() {
+            FtpEntryField::Kind | FtpEntryField::Size | FtpEntryField::Timestamp => {
+                err.with_progress(0)
+            }
+            _ => err,
+        })
+    }
+}
+
+/// Parses the number from the given group, returns its offset on failure.
+fn number<T: std::str::FromStr>(caps: &Captures, name: &str) -> Result<T, usize> {
+    let v = caps.name(name).unwrap();
+    v.as_str().parse().map_err(|_| v.start())
+}
+
+/// Parses `12-Jul-99 11:47:36` form of the timestamp.
+fn parse_date(caps: &Captures) -> Option<FtpEntryDate> {
+    let number = |s| caps.name(s).and_then(|v| v.as_str().parse::<u8>().ok());
+
+    let year: u16 = caps.name("year")?.as_str().parse().ok()?;
+    FtpEntryDate::from_ymd_hms(
+        year + if year < 70 { 2000 } else { 1900 },
+        month_from_abbr(caps.name("month")?.as_str())?,
+        number("day")?,
+        number("hour")?,
+        number("minute")?,
+        number("second")?,
+    )
+}
@@ -0,0 +1,272 @@
+use std::convert::TryFrom;
+
+use super::*;
+
+/// Value of the `type` fact of the `MLSD` entry.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FtpEntryMlsdType {
+    /// A file.
+    File,
+    /// A directory.
+    Dir,
+    /// The listed directory itself.
+    Cdir,
+    /// The parent of the listed directory.
+    Pdir,
+    /// Server specific type, e.g. `OS.unix=slink:/etc/target`.
+    Os(String),
+}
+
+impl From<&str> for FtpEntryMlsdType {
+    fn from(value: &str) -> Self {
+        match value.to_ascii_lowercase().as_str() {
+            "file" => Self::File,
+            "dir" => Self::Dir,
+            "cdir" => Self::Cdir,
+            "pdir" => Self::Pdir,
+            _ => Self::Os(value.to_string()),
+        }
+    }
+}
+
+/// Value of the `perm` fact of the `MLSD` entry.
+/// Shows what the current user is allowed to do with the entry.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct FtpEntryMlsdPermissions {
+    /// `a`: the file may be appended to.
+    pub append: bool,
+    /// `c`: files may be created in the directory.
+    pub create: bool,
+    /// `d`: the entry may be deleted.
+    pub delete: bool,
+    /// `e`: the directory may be entered.
+    pub enter: bool,
+    /// `f`: the entry may be renamed.
+    pub rename: bool,
+    /// `l`: the directory may be listed.
+    pub list: bool,
+    /// `m`: directories may be created in the directory.
+    pub mkdir: bool,
+    /// `p`: entries of the directory may be deleted.
+    pub purge: bool,
+    /// `r`: the file may be retrieved.
+    pub retrieve: bool,
+    /// `w`: the file may be stored.
+    pub store: bool,
+}
+
+impl From<&str> for FtpEntryMlsdPermissions {
+    fn from(value: &str) -> Self {
+        let mut perm = Self::default();
+        for c in value.chars() {
+            match c.to_ascii_lowercase() {
+                'a' => perm.append = true,
+                'c' => perm.create = true,
+                'd' => perm.delete = true,
+                'e' => perm.enter = true,
+                'f' => perm.rename = true,
+                'l' => perm.list = true,
+                'm' => perm.mkdir = true,
+                'p' => perm.purge = true,
+                'r' => perm.retrieve = true,
+                'w' => perm.store = true,
+                _ => {}
+            }
+        }
+
+        perm
+    }
+}
+
+/// Represents entry of the `MLSD` or `MLST` response (RFC 3659).
+///
+/// Dates of the entry are always in UTC.
+#[derive(Debug)]
+pub struct FtpEntryMlsd {
+    kind: FtpEntryKind,
+    name: String,
+    size: u64,
+    date: Option<FtpEntryDate>,
+    date_str: String,
+    /// The `type` fact.
+    pub entry_type: Option<FtpEntryMlsdType>,
+    /// The `create` fact.
+    pub create: Option<FtpEntryDate>,
+    /// The `unique` fact, identifies the entry on the server.
+    pub unique: Option<String>,
+    /// The `perm` fact.
+    pub perm: Option<FtpEntryMlsdPermissions>,
+    /// The `UNIX.mode` fact.
+    pub unix_mode: Option<u32>,
+    /// The `UNIX.owner` fact.
+    pub unix_owner: Option<String>,
+    /// The `UNIX.group` fact.
+    pub unix_group: Option<String>,
+    /// The `lang` fact.
+    pub lang: Option<String>,
+    /// The `media-type` fact.
+    pub media_type: Option<String>,
+    /// Facts not listed above, as sent by the server.
+    pub other_facts: Vec<(String, String)>,
+}
+
+impl FtpEntryMlsd {
+    /// Represents parsed string as entry of the `MLSD` response.
+    pub fn new(string: &str) -> Option<Self> {
+        FtpEntryMlsd::try_from(string).ok()
+    }
+}
+
+impl FtpEntryInfo for FtpEntryMlsd {
+    fn kind(&self) -> FtpEntryKind {
+        self.kind
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn size(&self) -> u64 {
+        self.size
+    }
+
+    fn date(&self) -> Option<FtpEntryDate> {
+        self.date
+    }
+
+    fn date_str(&self) -> &str {
+        &self.date_str
+    }
+}
+
+impl TryFrom<&str> for FtpEntryMlsd {
+    type Error = FtpEntryError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let error = |field, offset| FtpEntryError::new(Some(FtpEntryFormat::Mlsd), field, offset);
+
+        // `MLST` sends the entry with a leading space.
+        let start = if value.starts_with(' ') { 1 } else { 0 };
+        let facts_end = value[start..]
+            .find(' ')
+            .map_or(value.len(), |idx| start + idx);
+
+        if !value[start..facts_end].ends_with(';') {
+            return Err(error(FtpEntryField::Fact, start));
+        }
+
+        let mut facts = vec![];
+        let mut offset = start;
+        for fact in value[start..facts_end - 1].split(';') {
+            match fact.find('=') {
+                Some(idx) if idx > 0 => {
+                    facts.push((&fact[..idx], &fact[idx + 1..], offset + idx + 1))
+                }
+                _ => return Err(error(FtpEntryField::Fact, offset)),
+            }
+            offset += fact.len() + 1;
+        }
+
+        let name = value.get(facts_end + 1..).unwrap_or_default();
+        if name.is_empty() {
+            return Err(error(FtpEntryField::Name, value.len()));
+        }
+
+        let error = |field, offset| error(field, offset).with_progress(value.len());
+
+        let mut entry = Self {
+            kind: FtpEntryKind::UNKNOWN,
+            name: name.to_string(),
+            size: 0,
+            date: None,
+            date_str: String::new(),
+            entry_type: None,
+            create: None,
+            unique: None,
+            perm: None,
+            unix_mode: None,
+            unix_owner: None,
+            unix_group: None,
+            lang: None,
+            media_type: None,
+            other_facts: vec![],
+        };
+
+        for (fact, fact_value, offset) in facts {
+            match fact.to_ascii_lowercase().as_str() {
+                "type" => {
+                    let entry_type = FtpEntryMlsdType::from(fact_value);
+                    entry.kind = match &entry_type {
+                        FtpEntryMlsdType::File => FtpEntryKind::File,
+                        FtpEntryMlsdType::Dir | FtpEntryMlsdType::Cdir | FtpEntryMlsdType::Pdir => {
+                            FtpEntryKind::Directory
+                        }
+                        FtpEntryMlsdType::Os(os) => {
+                            let os = os.to_ascii_lowercase();
+                            if os.starts_with("os.unix=slink") || os.starts_with("os.unix=symlink")
+                            {
+                                FtpEntryKind::Symlink
+                            } else {
+                                FtpEntryKind::UNKNOWN
+                            }
+                        }
+                    };
+                    entry.entry_type = Some(entry_type);
+                }
+                "size" => {
+                    entry.size = fact_value
+                        .parse()
+                        .map_err(|_| error(FtpEntryField::Size, offset))?;
+                }
+                "modify" => {
+                    entry.date = Some(
+                        parse_time_val(fact_value)
+                            .ok_or_else(|| error(FtpEntryField::Timestamp, offset))?,
+                    );
+                    entry.date_str = fact_value.to_string();
+                }
+                "create" => {
+                    entry.create = Some(
+                        parse_time_val(fact_value)
+                            .ok_or_else(|| error(FtpEntryField::Timestamp, offset))?,
+                    );
+                }
+                "unique" => entry.unique = Some(fact_value.to_string()),
+                "perm" => entry.perm = Some(fact_value.into()),
+                "unix.mode" => {
+                    entry.unix_mode = Some(
+                        u32::from_str_radix(fact_value, 8)
+                            .map_err(|_| error(FtpEntryField::Permissions, offset))?,
+                    );
+                }
+                "unix.owner" => entry.unix_owner = Some(fact_value.to_string()),
+                "unix.group" => entry.unix_group = Some(fact_value.to_string()),
+                "lang" => entry.lang = Some(fact_value.to_string()),
+                "media-type" => entry.media_type = Some(fact_value.to_string()),
+                _ => entry
+                    .other_facts
+                    .push((fact.to_string(), fact_value.to_string())),
+            }
+        }
+
+        Ok(entry)
+    }
+}
+
+/// Parses `YYYYMMDDHHMMSS[.sss]` timestamp, fractions of the second are dropped.
+fn parse_time_val(value: &str) -> Option<FtpEntryDate> {
+    let value = value.split('.').next().unwrap();
+    if value.len() != 14 || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let number = |range: std::ops::Range<usize>| value[range].parse::<u8>().unwrap();
+    FtpEntryDate::from_ymd_hms(
+        value[..4].parse().unwrap(),
+        number(4..6),
+        number(6..8),
+        number(8..10),
+        number(10..12),
+        number(12..14),
+    )
+}
@@ -4,28 +4,53 @@ use ::regex::Regex;
 
 use super::*;
 
+/// Patterns of the fields in the order they appear in the line.
+const FIELDS: [(FtpEntryField, &str); 4] = [
+    (
+        FtpEntryField::Timestamp,
+        r"
+        ^(?:(?P<iyear>\d{4})[-/.](?P<imonth>\d{1,2})[-/.](?P<iday>\d{1,2})|
+            (?P<part1>\d{1,2})[-/.](?P<part2>\d{1,2})[-/.](?P<year>\d{2,4}))",
+    ),
+    (
+        FtpEntryField::Timestamp,
+        r"\s+(?P<hour>\d{1,2}):(?P<minute>\d{2})(?:\s{0,1}(?P<ampm>[AaMmPp]{1,2}))?",
+    ),
+    (
+        FtpEntryField::Size,
+        r"
+        \s+(?:(?P<size>\d{1,3}(?:[,.'\u{a0}\u{202f}]\d{3})+|\d+)|
+            (?P<isdir><DIR>)|
+            (?P<islink><(?:JUNCTION|SYMLINKD?)>))",
+    ),
+    (FtpEntryField::Name, r"\s+(?P<name>.+)$"),
+];
+
 lazy_static! {
-    static ref RELIST: Regex = Regex::new(
-        r"(?x)
-        ^(?P<month>\d{2})(?:\-|/)
-        (?P<date>\d{2})(?:\-|/)
-        (?P<year>\d{2,4})\s+
-        (?P<hour>\d{2}):(?P<minute>\d{2})\s{0,1}(?P<ampm>[AaMmPp]{1,2})\s+
-        (?:(?P<size>\d+)|(?P<isdir><DIR>))\s+
-        (?P<name>.+)$
-        "
-    )
-    .unwrap();
+    static ref RELIST: Regex = whole(&FIELDS);
+    static ref REPREFIXES: Vec<(FtpEntryField, Regex)> = prefixes(&FIELDS);
 }
 
-/// Represents entry from Msdos-like FTP server.
+/// Represents entry from Msdos-like FTP server, e.g. Windows NT / IIS:
+/// ```text
+/// 08-22-18  02:05PM       <DIR>          Test
+/// 2018-08-22  14:05       <DIR>          wwwroot
+/// 08-22-18  02:05PM    <JUNCTION>        Documents and Settings [C:\Users]
+/// ```
+///
+/// Both 12-hour and 24-hour times are accepted. Dates with the year first
+/// are read as `YYYY-MM-DD`; the order of day and month in other dates
+/// is given by [`FtpEntryDateOrder`], see [`parse_with_date_order`](#method.parse_with_date_order).
 #[derive(Debug)]
 pub struct FtpEntryMsdos {
     kind: FtpEntryKind,
     name: String,
-    size: usize,
-    // date: NaiveDateTime,
+    size: u64,
+    date: FtpEntryDate,
     date_str: String,
+    /// For `<JUNCTION>`, `<SYMLINK>` and `<SYMLINKD>` entries,
+    /// this is the target in square brackets after the name, if the server sent it.
+    pub target: Option<String>,
 }
 
 impl FtpEntryMsdos {
@@ -33,6 +58,22 @@ impl FtpEntryMsdos {
     pub fn new(string: &str) -> Option<Self> {
         FtpEntryMsdos::try_from(string).ok()
     }
+
+    /// Parses the string reading numeric dates like `08-05-18` in the given order.
+    /// [`TryFrom`] uses [`FtpEntryDateOrder::MonthDay`].
+    /// ```rust
+    /// # use ftp_cmd_list_parse::{FtpEntryDate, FtpEntryDateOrder, FtpEntryInfo, FtpEntryMsdos};
+    /// let row = "08-05-18  14:05       <DIR>          Test";
+    /// let entry = FtpEntryMsdos::parse_with_date_order(row, FtpEntryDateOrder::DayMonth).unwrap();
+    ///
+    /// assert_eq!(entry.date(), FtpEntryDate::from_ymd_hm(2018, 5, 8, 14, 5));
+    /// ```
+    pub fn parse_with_date_order(
+        value: &str,
+        order: FtpEntryDateOrder,
+    ) -> Result<Self, FtpEntryError> {
+        FtpEntryMsdosRef::parse_with_date_order(value, order).map(|entry| entry.to_owned())
+    }
 }
 
 impl FtpEntryInfo for FtpEntryMsdos {
@@ -44,13 +85,13 @@ impl FtpEntryInfo for FtpEntryMsdos {
         &self.name
     }
 
-    fn size(&self) -> usize {
+    fn size(&self) -> u64 {
         self.size
     }
 
-    // fn date(&self) -> NaiveDateTime {
-    //     self.date
-    // }
+    fn date(&self) -> Option<FtpEntryDate> {
+        Some(self.date)
+    }
 
     fn date_str(&self) -> &str {
         &self.date_str
@@ -58,63 +99,182 @@ impl FtpEntryInfo for FtpEntryMsdos {
 }
 
 impl TryFrom<&str> for FtpEntryMsdos {
-    type Error = ();
+    type Error = FtpEntryError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        if let Some(caps) = RELIST.captures(&value) {
+        FtpEntryMsdos::parse_with_date_order(value, FtpEntryDateOrder::default())
+    }
+}
+
+/// Borrowed counterpart of [`FtpEntryMsdos`]: the name and the target point
+/// into the parsed line, the normalized date string is kept inline.
+/// Nothing is allocated.
+#[derive(Debug, Clone)]
+pub struct FtpEntryMsdosRef<'a> {
+    kind: FtpEntryKind,
+    name: &'a str,
+    size: u64,
+    date: FtpEntryDate,
+    date_str: DateStr,
+    /// For `<JUNCTION>`, `<SYMLINK>` and `<SYMLINKD>` entries,
+    /// this is the target in square brackets after the name, if the server sent it.
+    pub target: Option<&'a str>,
+}
+
+impl<'a> FtpEntryMsdosRef<'a> {
+    /// Copies the borrowed strings into a new [`FtpEntryMsdos`].
+    pub fn to_owned(&self) -> FtpEntryMsdos {
+        FtpEntryMsdos {
+            kind: self.kind,
+            name: self.name.to_string(),
+            size: self.size,
+            date: self.date,
+            date_str: self.date_str.as_str().to_string(),
+            target: self.target.map(str::to_string),
+        }
+    }
+
+    /// Parses the string reading numeric dates like `08-05-18` in the given order,
+    /// see [`FtpEntryMsdos::parse_with_date_order`].
+    pub fn parse_with_date_order(
+        value: &'a str,
+        order: FtpEntryDateOrder,
+    ) -> Result<Self, FtpEntryError> {
+        let error = |field, offset| {
+            FtpEntryError::new(Some(FtpEntryFormat::Msdos), field, offset)
+                .with_progress(value.len())
+        };
+
+        if let Some(caps) = RELIST.captures(value) {
             let as_str = |s| caps.name(s).unwrap().as_str();
 
-            let name = as_str("name");
-            let kind = caps
-                .name("isdir")
-                .map_or(FtpEntryKind::File, |_| FtpEntryKind::Directory);
-            let size = caps
-                .name("size")
-                .map(|s| s.as_str().parse::<usize>())
-                .map_or(0, |r| r.map_or(0, |size| size));
-
-            let date_str = {
-                let month: u8 = as_str("month").parse().unwrap();
-                let date: u8 = as_str("date").parse().unwrap();
-                let year: u32 = match (as_str("year").len(), as_str("year").parse::<u32>().unwrap())
-                {
-                    (len, year) if len < 4 => year + if year < 70 { 2000 } else { 1900 },
-                    (_, year) => year,
+            let (kind, name, target) = if caps.name("islink").is_some() {
+                let name = as_str("name");
+                match name.strip_suffix(']').and_then(|v| v.rsplit_once(" [")) {
+                    Some((name, target)) => (FtpEntryKind::Symlink, name, Some(target)),
+                    None => (FtpEntryKind::Symlink, name, None),
+                }
+            } else if caps.name("isdir").is_some() {
+                (FtpEntryKind::Directory, as_str("name"), None)
+            } else {
+                (FtpEntryKind::File, as_str("name"), None)
+            };
+            let size = match caps.name("size") {
+                Some(size) => parse_size(size.as_str())
+                    .ok_or_else(|| error(FtpEntryField::Size, size.start()))?,
+                None => 0,
+            };
+
+            let (date, date_str) = {
+                let (month, day, year): (u8, u8, u32) = match caps.name("iyear") {
+                    Some(year) => (
+                        as_str("imonth").parse().unwrap(),
+                        as_str("iday").parse().unwrap(),
+                        year.as_str().parse().unwrap(),
+                    ),
+                    None => {
+                        let (part1, part2) = (
+                            as_str("part1").parse().unwrap(),
+                            as_str("part2").parse().unwrap(),
+                        );
+                        let year = match (as_str("year").len(), as_str("year").parse().unwrap()) {
+                            (len, year) if len < 4 => year + if year < 70 { 2000 } else { 1900 },
+                            (_, year) => year,
+                        };
+                        match order {
+                            FtpEntryDateOrder::MonthDay => (part1, part2, year),
+                            FtpEntryDateOrder::DayMonth => (part2, part1, year),
+                        }
+                    }
                 };
                 let mut hour: u8 = as_str("hour").parse().unwrap();
                 let minute: u8 = as_str("minute").parse().unwrap();
-                if hour < 12
-                    && as_str("ampm")
-                        .bytes()
-                        .next()
-                        .unwrap()
-                        .eq_ignore_ascii_case(&b'p')
-                {
-                    hour += 12;
-                } else if hour == 12
-                    && as_str("ampm")
-                        .bytes()
-                        .next()
-                        .unwrap()
-                        .eq_ignore_ascii_case(&b'a')
-                {
-                    hour = 0;
+                // Without the AM/PM suffix the time is on a 24-hour clock.
+                match caps.name("ampm").map(|v| v.as_str().as_bytes()[0]) {
+                    Some(ampm) if hour < 12 && ampm.eq_ignore_ascii_case(&b'p') => hour += 12,
+                    Some(ampm) if hour == 12 && ampm.eq_ignore_ascii_case(&b'a') => hour = 0,
+                    _ => {}
                 }
 
-                format!(
+                // Point at the date if it is invalid on its own, otherwise at the time.
+                let date_start = caps.name("iyear").or_else(|| caps.name("part1")).unwrap();
+                let year = u16::try_from(year)
+                    .ok()
+                    .filter(|&year| FtpEntryDate::from_ymd(year, month, day).is_some())
+                    .ok_or_else(|| error(FtpEntryField::Timestamp, date_start.start()))?;
+                let hour_start = caps.name("hour").unwrap().start();
+                let date = FtpEntryDate::from_ymd_hm(year, month, day, hour, minute)
+                    .ok_or_else(|| error(FtpEntryField::Timestamp, hour_start))?;
+
+                let mut date_str = DateStr::default();
+                write!(
+                    date_str,
                     "{}-{:02}-{:02}T{:02}:{:02}",
-                    year, month, date, hour, minute
+                    year, month, day, hour, minute
                 )
+                .map_err(|_| error(FtpEntryField::Timestamp, date_start.start()))?;
+
+                (date, date_str)
             };
 
             return Ok(Self {
-                name: name.to_owned(),
+                name,
                 kind,
                 size,
+                date,
                 date_str,
+                target,
             });
         }
 
-        Err(())
+        Err(diagnose(value, FtpEntryFormat::Msdos, &REPREFIXES))
     }
 }
+
+impl FtpEntryInfo for FtpEntryMsdosRef<'_> {
+    fn kind(&self) -> FtpEntryKind {
+        self.kind
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn size(&self) -> u64 {
+        self.size
+    }
+
+    fn date(&self) -> Option<FtpEntryDate> {
+        Some(self.date)
+    }
+
+    fn date_str(&self) -> &str {
+        self.date_str.as_str()
+    }
+}
+
+impl<'a> TryFrom<&'a str> for FtpEntryMsdosRef<'a> {
+    type Error = FtpEntryError;
+
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        FtpEntryMsdosRef::parse_with_date_order(value, FtpEntryDateOrder::default())
+    }
+}
+
+/// Parses the size, which may be grouped by thousands like `1,234,567`,
+/// `1.234.567`, `1'234'567` or with non-breaking spaces.
+/// Every group must use the same separator, and the size must fit into `u64`.
+fn parse_size(value: &str) -> Option<u64> {
+    let mut separator = None;
+    let mut size: u64 = 0;
+
+    for c in value.chars() {
+        match c.to_digit(10) {
+            Some(digit) => size = size.checked_mul(10)?.checked_add(u64::from(digit))?,
+            None if *separator.get_or_insert(c) != c => return None,
+            None => {}
+        }
+    }
+
+    Some(size)
+}
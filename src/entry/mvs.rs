@@ -0,0 +1,395 @@
+use std::convert::TryFrom;
+
+use ::regex::{Captures, Regex};
+
+use super::*;
+
+/// Patterns of the fields of the dataset line in the order they appear in the line.
+const DATASET_FIELDS: [(FtpEntryField, &str); 5] = [
+    (FtpEntryField::Volume, r"^(?P<volume>\S+)\s+(?P<unit>\S+)"),
+    (
+        FtpEntryField::Timestamp,
+        r"\s+(?P<referred>(?P<year>\d{4})/(?P<month>\d{2})/(?P<day>\d{2})|\*\*NONE\*\*)",
+    ),
+    (FtpEntryField::Size, r"\s+(?P<ext>\d+)\s+(?P<used>\d+)"),
+    (
+        FtpEntryField::RecordFormat,
+        r"\s+(?P<recfm>\S+)\s+(?P<lrecl>\d+|\?)\s+(?P<blksize>\d+|\?)\s+(?P<dsorg>\S+)",
+    ),
+    (FtpEntryField::Name, r"\s+(?P<dsname>\S+)\s*$"),
+];
+
+/// Patterns of the fields of the member line in the order they appear in the line.
+const MEMBER_FIELDS: [(FtpEntryField, &str); 5] = [
+    (FtpEntryField::Name, r"^(?P<name>\S{1,8})"),
+    (FtpEntryField::Version, r"\s+(?P<vv>\d{2})\.(?P<mm>\d{2})"),
+    (
+        FtpEntryField::Timestamp,
+        r"
+        \s+(?P<created>(?P<cyear>\d{4})/(?P<cmonth>\d{2})/(?P<cday>\d{2}))
+        \s+(?P<changed>(?P<year>\d{4})/(?P<month>\d{2})/(?P<day>\d{2})
+        \s+(?P<hour>\d{2}):(?P<minute>\d{2})(?::(?P<second>\d{2}))?)",
+    ),
+    (
+        FtpEntryField::Size,
+        r"\s+(?P<size>\d+)\s+(?P<init>\d+)\s+(?P<mod>\d+)",
+    ),
+    (FtpEntryField::Owner, r"\s+(?P<id>\S+)\s*$"),
+];
+
+lazy_static! {
+    static ref REDATASET: Regex = whole(&DATASET_FIELDS);
+    static ref REDATASETPREFIXES: Vec<(FtpEntryField, Regex)> = prefixes(&DATASET_FIELDS);
+    static ref RESPECIAL: Regex = Regex::new(
+        r"(?x)
+        ^(?:(?P<migrated>Migrated)|
+            (?P<pseudo>Pseudo\s+Directory)|
+            (?P<volume>\S+)\s+Not\s+Direct\s+Access\s+Device)
+        \s+(?P<dsname>\S+)\s*$"
+    )
+    .unwrap();
+    static ref REDATASETHEADER: Regex = Regex::new(
+        r"^Volume\s+Unit\s+Referred\s+Ext\s+Used\s+Recfm\s+Lrecl\s+BlkSz\s+Dsorg\s+Dsname\s*$"
+    )
+    .unwrap();
+    static ref REMEMBER: Regex = whole(&MEMBER_FIELDS);
+    static ref REMEMBERPREFIXES: Vec<(FtpEntryField, Regex)> = prefixes(&MEMBER_FIELDS);
+    static ref REMEMBERHEADER: Regex =
+        Regex::new(r"^\s*Name\s+VV\.MM\s+Created\s+Changed\s+Size\s+Init\s+Mod\s+Id\s*$").unwrap();
+}
+
+/// Represents dataset from IBM z/OS (MVS) FTP server:
+/// ```text
+/// Volume Unit    Referred Ext Used Recfm Lrecl BlkSz Dsorg Dsname
+/// WYOSPT 3420   2003/05/21  1  200  FB      80  8053  PS  BPL.CICS.ONLINE
+/// Migrated                                                OLD.DATASET
+/// ```
+///
+/// Partitioned datasets (`PO`) and pseudo directories are reported as
+/// [`FtpEntryKind::Directory`], as their members are listed like files.
+#[derive(Debug)]
+pub struct FtpEntryMvsDataset {
+    kind: FtpEntryKind,
+    name: String,
+    date: Option<FtpEntryDate>,
+    date_str: String,
+    /// Serial of the volume the dataset resides on.
+    pub volume: Option<String>,
+    /// Device type of the volume.
+    pub unit: Option<String>,
+    /// Number of extents.
+    pub extents: Option<u32>,
+    /// Number of used tracks.
+    pub used: Option<u32>,
+    /// Record format, e.g. `FB` or `VB`.
+    pub recfm: Option<String>,
+    /// Logical record length.
+    pub lrecl: Option<u32>,
+    /// Block size.
+    pub blksize: Option<u32>,
+    /// Dataset organization, e.g. `PS` or `PO`.
+    pub dsorg: Option<String>,
+    /// True if the dataset was migrated by HSM and its attributes are unknown.
+    pub migrated: bool,
+}
+
+impl FtpEntryMvsDataset {
+    /// Represents parsed string as dataset of a MVS FTP server.
+    pub fn new(string: &str) -> Option<Self> {
+        FtpEntryMvsDataset::try_from(string).ok()
+    }
+
+    /// Returns true if the line is the header of the dataset listing,
+    /// that names the columns.
+    pub fn is_header(line: &str) -> bool {
+        REDATASETHEADER.is_match(line)
+    }
+
+    fn empty(kind: FtpEntryKind, name: &str) -> Self {
+        Self {
+            kind,
+            name: name.to_string(),
+            date: None,
+            date_str: String::new(),
+            volume: None,
+            unit: None,
+            extents: None,
+            used: None,
+            recfm: None,
+            lrecl: None,
+            blksize: None,
+            dsorg: None,
+            migrated: false,
+        }
+    }
+}
+
+impl FtpEntryInfo for FtpEntryMvsDataset {
+    fn kind(&self) -> FtpEntryKind {
+        self.kind
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// MVS servers do not send size of datasets in bytes, so it is always 0.
+    fn size(&self) -> u64 {
+        0
+    }
+
+    /// Returns the date the dataset was last referred.
+    fn date(&self) -> Option<FtpEntryDate> {
+        self.date
+    }
+
+    fn date_str(&self) -> &str {
+        &self.date_str
+    }
+}
+
+impl TryFrom<&str> for FtpEntryMvsDataset {
+    type Error = FtpEntryError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let error = |field, offset| {
+            FtpEntryError::new(Some(FtpEntryFormat::Mvs), field, offset).with_progress(value.len())
+        };
+
+        if let Some(caps) = RESPECIAL.captures(value) {
+            let dsname = &caps["dsname"];
+            let entry = if caps.name("migrated").is_some() {
+                Self {
+                    migrated: true,
+                    ..Self::empty(FtpEntryKind::File, dsname)
+                }
+            } else if caps.name("pseudo").is_some() {
+                Self::empty(FtpEntryKind::Directory, dsname)
+            } else {
+                Self {
+                    volume: Some(caps["volume"].to_string()),
+                    ..Self::empty(FtpEntryKind::File, dsname)
+                }
+            };
+
+            return Ok(entry);
+        }
+
+        if let Some(caps) = REDATASET.captures(value) {
+            let number = |s| match caps.name(s).unwrap() {
+                v if v.as_str() == "?" => Ok(None),
+                v => v.as_str().parse().map(Some).map_err(|_| v.start()),
+            };
+
+            let referred = caps.name("referred").unwrap();
+            let date = match caps.name("year") {
+                Some(_) => Some(
+                    parse_date(&caps, "year", "month", "day")
+                        .ok_or_else(|| error(FtpEntryField::Timestamp, referred.start()))?,
+                ),
+                None => None,
+            };
+
+            let dsorg = caps["dsorg"].to_string();
+            let kind = if dsorg.starts_with("PO") {
+                FtpEntryKind::Directory
+            } else {
+                FtpEntryKind::File
+            };
+
+            return Ok(Self {
+                kind,
+                name: caps["dsname"].to_string(),
+                date,
+                date_str: referred.as_str().to_string(),
+                volume: Some(caps["volume"].to_string()),
+                unit: Some(caps["unit"].to_string()),
+                extents: number("ext").map_err(|offset| error(FtpEntryField::Size, offset))?,
+                used: number("used").map_err(|offset| error(FtpEntryField::Size, offset))?,
+                recfm: Some(caps["recfm"].to_string()).filter(|v| v != "?"),
+                lrecl: number("lrecl")
+                    .map_err(|offset| error(FtpEntryField::RecordFormat, offset))?,
+                blksize: number("blksize")
+                    .map_err(|offset| error(FtpEntryField::RecordFormat, offset))?,
+                dsorg: Some(dsorg).filter(|v| v != "?"),
+                migrated: false,
+            });
+        }
+
+        // Any two words look like the volume and the unit, so they do not
+        // count as progress against other formats.
+        let err = diagnose(value, FtpEntryFormat::Mvs, &REDATASETPREFIXES);
+        Err(match err.field() {
+            FtpEntryField::Timestamp => err.with_progress(0),
+            _ => err,
+        })
+    }
+}
+
+/// Represents member of a partitioned dataset from IBM z/OS (MVS) FTP server:
+/// ```text
+///  Name     VV.MM   Created       Changed      Size  Init   Mod   Id
+/// MEMBER1   01.01 2002/09/10 2002/09/10 16:09    15    15     0 USERID
+/// ```
+///
+/// Members without statistics are sent by name only. Such lines can be
+/// parsed only as a part of the whole listing, see [`FtpListing`](crate::FtpListing).
+#[derive(Debug)]
+pub struct FtpEntryMvsMember {
+    name: String,
+    date: Option<FtpEntryDate>,
+    date_str: String,
+    /// Version and modification level, `VV.MM`.
+    pub version: Option<(u8, u8)>,
+    /// The date the member was created.
+    pub created: Option<FtpEntryDate>,
+    /// Current number of records.
+    pub records: Option<u32>,
+    /// Initial number of records.
+    pub init: Option<u32>,
+    /// Number of modified records.
+    pub modified: Option<u32>,
+    /// Id of the user who last changed the member.
+    pub id: Option<String>,
+}
+
+impl FtpEntryMvsMember {
+    /// Represents parsed string as member of a partitioned dataset of a MVS FTP server.
+    pub fn new(string: &str) -> Option<Self> {
+        FtpEntryMvsMember::try_from(string).ok()
+    }
+
+    /// Returns true if the line is the header of the member listing,
+    /// that names the columns.
+    pub fn is_header(line: &str) -> bool {
+        REMEMBERHEADER.is_match(line)
+    }
+
+    /// Returns a member without statistics, the line contains only its name.
+    pub(crate) fn name_only(line: &str) -> Option<Self> {
+        let name = line.trim();
+        if name.is_empty() || name.len() > 8 || name.contains(char::is_whitespace) {
+            return None;
+        }
+
+        Some(Self {
+            name: name.to_string(),
+            date: None,
+            date_str: String::new(),
+            version: None,
+            created: None,
+            records: None,
+            init: None,
+            modified: None,
+            id: None,
+        })
+    }
+}
+
+impl FtpEntryInfo for FtpEntryMvsMember {
+    fn kind(&self) -> FtpEntryKind {
+        FtpEntryKind::File
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// MVS servers do not send size of members in bytes, so it is always 0.
+    /// See [`records`](#structfield.records) instead.
+    fn size(&self) -> u64 {
+        0
+    }
+
+    /// Returns the date the member was last changed.
+    fn date(&self) -> Option<FtpEntryDate> {
+        self.date
+    }
+
+    fn date_str(&self) -> &str {
+        &self.date_str
+    }
+}
+
+impl TryFrom<&str> for FtpEntryMvsMember {
+    type Error = FtpEntryError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let error = |field, offset| {
+            FtpEntryError::new(Some(FtpEntryFormat::Mvs), field, offset).with_progress(value.len())
+        };
+
+        if let Some(caps) = REMEMBER.captures(value) {
+            let number = |s| {
+                let v = caps.name(s).unwrap();
+                v.as_str()
+                    .parse()
+                    .map(Some)
+                    .map_err(|_| error(FtpEntryField::Size, v.start()))
+            };
+
+            let created = caps.name("created").unwrap();
+            let changed = caps.name("changed").unwrap();
+
+            return Ok(Self {
+                name: caps["name"].to_string(),
+                date: Some(
+                    parse_date(&caps, "year", "month", "day")
+                        .and_then(|date| {
+                            let number = |s| caps.name(s).map(|v| v.as_str().parse::<u8>().ok());
+                            let (hour, minute) = (number("hour")??, number("minute")??);
+                            match number("second") {
+                                Some(second) => FtpEntryDate::from_ymd_hms(
+                                    date.year()?,
+                                    date.month(),
+                                    date.day(),
+                                    hour,
+                                    minute,
+                                    second?,
+                                ),
+                                None => FtpEntryDate::from_ymd_hm(
+                                    date.year()?,
+                                    date.month(),
+                                    date.day(),
+                                    hour,
+                                    minute,
+                                ),
+                            }
+                        })
+                        .ok_or_else(|| error(FtpEntryField::Timestamp, changed.start()))?,
+                ),
+                date_str: changed
+                    .as_str()
+                    .split_whitespace()
+                    .collect::<Vec<_>>()
+                    .join(" "),
+                version: Some((caps["vv"].parse().unwrap(), caps["mm"].parse().unwrap())),
+                created: Some(
+                    parse_date(&caps, "cyear", "cmonth", "cday")
+                        .ok_or_else(|| error(FtpEntryField::Timestamp, created.start()))?,
+                ),
+                records: number("size")?,
+                init: number("init")?,
+                modified: number("mod")?,
+                id: Some(caps["id"].to_string()),
+            });
+        }
+
+        // Any word looks like the name, so it does not count as progress
+        // against other formats.
+        let err = diagnose(value, FtpEntryFormat::Mvs, &REMEMBERPREFIXES);
+        Err(match err.field() {
+            FtpEntryField::Version => err.with_progress(0),
+            _ => err,
+        })
+    }
+}
+
+/// Parses `2003/05/21` form of the date from the given groups.
+fn parse_date(caps: &Captures, year: &str, month: &str, day: &str) -> Option<FtpEntryDate> {
+    FtpEntryDate::from_ymd(
+        caps.name(year)?.as_str().parse().ok()?,
+        caps.name(month)?.as_str().parse().ok()?,
+        caps.name(day)?.as_str().parse().ok()?,
+    )
+}
@@ -0,0 +1,157 @@
+use std::convert::{TryFrom, TryInto};
+
+use ::regex::Regex;
+
+use super::*;
+
+/// Patterns of the fields in the order they appear in the line.
+const FIELDS: [(FtpEntryField, &str); 6] = [
+    (FtpEntryField::Kind, r"^(?P<type>[d-])"),
+    (
+        FtpEntryField::Permissions,
+        r"\s+\[(?P<rights>[RWCEAFMS-]{1,8})\]",
+    ),
+    (FtpEntryField::Owner, r"\s+(?P<owner>\S+)"),
+    (FtpEntryField::Size, r"\s+(?P<size>\d+)"),
+    (FtpEntryField::Timestamp, MONTH_DATE),
+    (FtpEntryField::Name, r"\s+(?P<name>.+)$"),
+];
+
+lazy_static! {
+    static ref RELIST: Regex = whole(&FIELDS);
+    static ref REPREFIXES: Vec<(FtpEntryField, Regex)> = prefixes(&FIELDS);
+}
+
+/// Effective trustee rights of the NetWare entry, e.g. `[RWCEAFMS]`.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct FtpEntryNetwareRights {
+    /// `S`: all rights to the entry and its children.
+    pub supervisor: bool,
+    /// `R`: the file may be read.
+    pub read: bool,
+    /// `W`: the file may be written.
+    pub write: bool,
+    /// `C`: entries may be created in the directory.
+    pub create: bool,
+    /// `E`: the entry may be erased.
+    pub erase: bool,
+    /// `M`: attributes of the entry may be modified.
+    pub modify: bool,
+    /// `F`: the directory may be listed.
+    pub file_scan: bool,
+    /// `A`: trustee rights of the entry may be changed.
+    pub access_control: bool,
+}
+
+impl From<&str> for FtpEntryNetwareRights {
+    fn from(value: &str) -> Self {
+        let mut rights = Self::default();
+        for c in value.chars() {
+            match c {
+                'S' => rights.supervisor = true,
+                'R' => rights.read = true,
+                'W' => rights.write = true,
+                'C' => rights.create = true,
+                'E' => rights.erase = true,
+                'M' => rights.modify = true,
+                'F' => rights.file_scan = true,
+                'A' => rights.access_control = true,
+                _ => {}
+            }
+        }
+
+        rights
+    }
+}
+
+/// Represents entry from NetWare / Novell FTP server:
+/// ```text
+/// d [RWCEAFMS] owner                512 Mar 03 14:04 dirname
+/// - [R----F--] owner               1024 Dec 21  2012 file.txt
+/// ```
+#[derive(Debug)]
+pub struct FtpEntryNetware {
+    kind: FtpEntryKind,
+    name: String,
+    size: u64,
+    date: FtpEntryDate,
+    date_str: String,
+    /// The effective rights of the current user for this entry.
+    pub rights: FtpEntryNetwareRights,
+    /// The user name that this entry belongs to.
+    pub owner: String,
+}
+
+impl FtpEntryNetware {
+    /// Represents parsed string as entry of a NetWare FTP server.
+    pub fn new(string: &str) -> Option<Self> {
+        FtpEntryNetware::try_from(string).ok()
+    }
+}
+
+impl FtpEntryInfo for FtpEntryNetware {
+    fn kind(&self) -> FtpEntryKind {
+        self.kind
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn size(&self) -> u64 {
+        self.size
+    }
+
+    fn date(&self) -> Option<FtpEntryDate> {
+        Some(self.date)
+    }
+
+    fn date_str(&self) -> &str {
+        &self.date_str
+    }
+}
+
+impl TryFrom<&str> for FtpEntryNetware {
+    type Error = FtpEntryError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let error = |field, offset| {
+            FtpEntryError::new(Some(FtpEntryFormat::Netware), field, offset)
+                .with_progress(value.len())
+        };
+
+        if let Some(caps) = RELIST.captures(value) {
+            let kind: FtpEntryKind = caps["type"]
+                .try_into()
+                .map_err(|_| error(FtpEntryField::Kind, 0))?;
+
+            let size = caps.name("size").unwrap();
+            let size = size
+                .as_str()
+                .parse()
+                .map_err(|_| error(FtpEntryField::Size, size.start()))?;
+
+            let timestamp = caps.name("timestamp").unwrap();
+            let date_str = timestamp
+                .as_str()
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ");
+
+            let date = parse_month_date(&caps)
+                .ok_or_else(|| error(FtpEntryField::Timestamp, timestamp.start()))?;
+
+            return Ok(Self {
+                kind,
+                name: caps["name"].to_string(),
+                size,
+                date,
+                date_str,
+                rights: caps["rights"].into(),
+                owner: caps["owner"].to_string(),
+            });
+        }
+
+        Err(diagnose(value, FtpEntryFormat::Netware, &REPREFIXES))
+    }
+}
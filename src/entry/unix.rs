@@ -1,29 +1,138 @@
-use std::convert::{TryFrom, TryInto};
+use std::{
+    borrow::Cow,
+    convert::{TryFrom, TryInto},
+};
 
-use ::regex::Regex;
+use ::regex::{Captures, Regex};
 
 use super::*;
 
-lazy_static! {
-    static ref RELIST: Regex = Regex::new(
-        r"(?x)
-        ^(?P<type>[bcdelfmpSs-])
+/// Patterns of the fields in the order they appear in the line.
+const FIELDS: [(FtpEntryField, &str); 8] = [
+    (FtpEntryField::Kind, r"^(?P<type>[bcdelfmpSs-])"),
+    (
+        FtpEntryField::Permissions,
+        r"
         (?P<permission>((r|-)(w|-)([xsStTL-]))((r|-)(w|-)([xsStTL-]))((r|-)(w|-)([xsStTL-])))
-        (?P<acl>([\+|@]))?\s+
-        (?P<inodes>\d+)\s+
-        (?P<owner>\d+|[A-Z]{1}\w+\s+[A-Z]{1}\w+|\w+|\S+)\s+
-        (?P<group>\d+|[A-Z]{1}[\w\\]+\s+[A-Z]{1}\w+|\w+|\S+)\s+
-        (?P<size>\d+(?:,\s*\d*)?)\s+
-        (?P<timestamp>((?P<month1>\w{3})\s+
-            (?P<date1>\d{1,2})\s+
-            (?P<hour>\d{1,2}):(?P<minute>\d{2}))|
-            ((?P<month2>\w{3})\s+
-                (?P<date2>\d{1,2})\s+
-                (?P<year>\d{4})))\s+
-        (?P<name>.+)$
-    "
-    )
-    .unwrap();
+        (?P<marker>[+@.])?",
+    ),
+    (FtpEntryField::LinkCount, r"\s+(?P<inodes>\d+)"),
+    (
+        FtpEntryField::Owner,
+        r"\s+(?P<owner>\d+|[A-Z]{1}\w+\s+[A-Z]{1}\w+|\w+|\S+)",
+    ),
+    (
+        FtpEntryField::Group,
+        r"\s+(?P<group>\d+|[A-Z]{1}[\w\\]+\s+[A-Z]{1}\w+|\w+|\S+)",
+    ),
+    (
+        FtpEntryField::Size,
+        r"
+        \s+(?P<size>(?P<hsize>(?P<number>\d+(?:[.,]\d+)?)(?P<unit>[KkMGTPE])(?P<binary>i)?B?)|
+            (?P<major>\d+),(?:\s*(?P<minor>0x[0-9A-Fa-f]+|\d+))?|
+            \d+)",
+    ),
+    (FtpEntryField::Timestamp, MONTH_DATE),
+    (FtpEntryField::Name, r"\s+(?P<name>.+)$"),
+];
+
+/// Patterns of the fields of `ls -o` and `ls -g` lines, which have
+/// a single ownership column. Tried only if [`FIELDS`] do not match.
+const SINGLE_FIELDS: [(FtpEntryField, &str); 7] = [
+    FIELDS[0],
+    FIELDS[1],
+    FIELDS[2],
+    (FtpEntryField::Owner, r"\s+(?P<ownership>\S+)"),
+    FIELDS[5],
+    FIELDS[6],
+    FIELDS[7],
+];
+
+/// Patterns of the fields for [`FtpEntryUnixColumns`]: the owner and the group
+/// are taken as a whole, and the whitespace before the name is kept.
+const ALIGNED_FIELDS: [(FtpEntryField, &str); 7] = [
+    FIELDS[0],
+    FIELDS[1],
+    FIELDS[2],
+    (FtpEntryField::Owner, r"\s+(?P<owners>\S.*?)"),
+    FIELDS[5],
+    FIELDS[6],
+    (FtpEntryField::Name, r"(?P<rest>\s.+)$"),
+];
+
+lazy_static! {
+    static ref RELIST: Regex = whole(&FIELDS);
+    static ref REPREFIXES: Vec<(FtpEntryField, Regex)> = prefixes(&FIELDS);
+    static ref RESINGLE: Regex = whole(&SINGLE_FIELDS);
+    static ref RESINGLEPREFIXES: Vec<(FtpEntryField, Regex)> = prefixes(&SINGLE_FIELDS);
+    static ref REALIGNED: Regex = whole(&ALIGNED_FIELDS);
+}
+
+/// Columns shared by all Unix lines of one listing, as `ls -l` pads
+/// every field to the same width.
+#[derive(Debug)]
+pub(crate) struct FtpEntryUnixColumns {
+    /// Column of the group in characters, if only one column can be it.
+    group: Option<usize>,
+    /// Number of whitespace characters between the timestamp and the name.
+    name_gap: usize,
+}
+
+impl FtpEntryUnixColumns {
+    /// Infers the columns from all lines of the listing.
+    /// Returns `None` if there are no Unix lines.
+    pub(crate) fn infer<'a>(lines: impl IntoIterator<Item = &'a str>) -> Option<Self> {
+        let mut owner_column = None;
+        let mut aligned = true;
+        let mut groups: Option<Vec<usize>> = None;
+        let mut name_gap: Option<usize> = None;
+
+        for line in lines {
+            let caps = match REALIGNED.captures(line) {
+                Some(caps) => caps,
+                None => continue,
+            };
+
+            let owners = caps.name("owners").unwrap();
+            let column = line[..owners.start()].chars().count();
+            aligned &= *owner_column.get_or_insert(column) == column;
+
+            // The group may begin at every word of the owner and the group.
+            let chars = owners.as_str().chars().collect::<Vec<_>>();
+            let starts = chars
+                .windows(2)
+                .enumerate()
+                .filter(|(_, pair)| pair[0].is_whitespace() && !pair[1].is_whitespace())
+                .map(|(idx, _)| column + idx + 1)
+                .collect::<Vec<_>>();
+            groups = Some(match groups {
+                Some(groups) => groups.into_iter().filter(|c| starts.contains(c)).collect(),
+                None => starts,
+            });
+
+            let gap = caps["rest"]
+                .chars()
+                .take_while(|c| c.is_whitespace())
+                .count();
+            name_gap = Some(name_gap.map_or(gap, |name_gap| name_gap.min(gap)));
+        }
+
+        Some(Self {
+            group: groups
+                .filter(|groups| aligned && groups.len() == 1)
+                .map(|groups| groups[0]),
+            name_gap: name_gap?,
+        })
+    }
+}
+
+/// Device number of the block or character device entry, e.g. `8,   0`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct FtpEntryUnixDevice {
+    pub major: u32,
+    /// Some BSD servers send the minor number in hex, e.g. `0x00010000`.
+    /// `None` if the server sent only the major number, e.g. `8,`.
+    pub minor: Option<u32>,
 }
 
 /// Represents entry from Unix-like FTP server.
@@ -31,22 +140,47 @@ lazy_static! {
 pub struct FtpEntryUnix {
     kind: FtpEntryKind,
     name: String,
-    size: usize,
-    // pub date: NaiveDateTime,
+    size: u64,
+    date: FtpEntryDate,
     date_str: String,
     /// For symlink entries, this is the symlink's target.
     pub target: Option<String>,
     /// True if the sticky bit is set for this entry.
     pub sticky: bool,
+    /// True if the set-user-ID bit is set for this entry.
+    pub setuid: bool,
+    /// True if the set-group-ID bit is set for this entry,
+    /// including the `L` mandatory locking flag.
+    pub setgid: bool,
+    /// True if mandatory locking is enabled for this entry (`L`).
+    pub mandatory_locking: bool,
     /// The various permissions for this entry.
     pub permissions: FtpEntryPermissions,
-    /// Marks extra ACL permission for this entry.
+    /// The number of hard links to this entry.
+    pub link_count: u64,
+    /// True if the entry has a POSIX ACL (`+`).
     pub acl: bool,
-    /// The user name or ID that this entry belongs to.
-    pub owner: String,
-    /// The group name or ID that this entry belongs to.
-    pub group: String,
+    /// True if the entry has macOS extended attributes (`@`).
+    pub xattr: bool,
+    /// True if the entry has an SELinux security context and nothing else (`.`).
+    pub selinux: bool,
+    /// The user name or ID that this entry belongs to,
+    /// `None` if the server sent a single ownership column.
+    pub owner: Option<String>,
+    /// The group name or ID that this entry belongs to,
+    /// `None` if the server sent a single ownership column.
+    pub group: Option<String>,
+    /// The only ownership column of `ls -o` and `ls -g` listings. Nothing
+    /// tells whether it is the owner or the group, so it is kept apart.
+    pub ownership: Option<String>,
+    /// For device entries, the device number as sent by the server
+    /// without whitespace, e.g. `8,0`.
     pub pointer: Option<String>,
+    /// For device entries, the parsed device number.
+    pub device: Option<FtpEntryUnixDevice>,
+    /// True if the server sent a human-readable size like `4.2M` (`ls -h`).
+    /// Such a size is rounded, so it must not be used for integrity checks.
+    pub approximate_size: bool,
 }
 
 impl FtpEntryUnix {
@@ -65,178 +199,362 @@ impl FtpEntryInfo for FtpEntryUnix {
         &self.name
     }
 
-    fn size(&self) -> usize {
+    fn size(&self) -> u64 {
         self.size
     }
 
-    // fn date(&self) -> NaiveDateTime {
-    //     self.date
-    // }
+    fn date(&self) -> Option<FtpEntryDate> {
+        Some(self.date)
+    }
 
     fn date_str(&self) -> &str {
         &self.date_str
     }
 }
 
+/// Borrowed counterpart of [`FtpEntryUnix`]: strings point into the parsed
+/// line and the normalized date string is kept inline. Only the device
+/// number is allocated when its whitespace has to be removed, e.g. `8,   0`.
+#[derive(Debug, Clone)]
+pub struct FtpEntryUnixRef<'a> {
+    kind: FtpEntryKind,
+    name: &'a str,
+    size: u64,
+    date: FtpEntryDate,
+    date_str: DateStr,
+    /// For symlink entries, this is the symlink's target.
+    pub target: Option<&'a str>,
+    /// True if the sticky bit is set for this entry.
+    pub sticky: bool,
+    /// True if the set-user-ID bit is set for this entry.
+    pub setuid: bool,
+    /// True if the set-group-ID bit is set for this entry,
+    /// including the `L` mandatory locking flag.
+    pub setgid: bool,
+    /// True if mandatory locking is enabled for this entry (`L`).
+    pub mandatory_locking: bool,
+    /// The various permissions for this entry.
+    pub permissions: FtpEntryPermissions,
+    /// The number of hard links to this entry.
+    pub link_count: u64,
+    /// True if the entry has a POSIX ACL (`+`).
+    pub acl: bool,
+    /// True if the entry has macOS extended attributes (`@`).
+    pub xattr: bool,
+    /// True if the entry has an SELinux security context and nothing else (`.`).
+    pub selinux: bool,
+    /// The user name or ID that this entry belongs to,
+    /// `None` if the server sent a single ownership column.
+    pub owner: Option<&'a str>,
+    /// The group name or ID that this entry belongs to,
+    /// `None` if the server sent a single ownership column.
+    pub group: Option<&'a str>,
+    /// The only ownership column of `ls -o` and `ls -g` listings,
+    /// see [`FtpEntryUnix::ownership`].
+    pub ownership: Option<&'a str>,
+    /// For device entries, the device number as sent by the server
+    /// without whitespace, e.g. `8,0`.
+    pub pointer: Option<Cow<'a, str>>,
+    /// For device entries, the parsed device number.
+    pub device: Option<FtpEntryUnixDevice>,
+    /// True if the server sent a human-readable size like `4.2M` (`ls -h`).
+    /// Such a size is rounded, so it must not be used for integrity checks.
+    pub approximate_size: bool,
+}
+
+impl FtpEntryUnixRef<'_> {
+    /// Copies the borrowed strings into a new [`FtpEntryUnix`].
+    pub fn to_owned(&self) -> FtpEntryUnix {
+        FtpEntryUnix {
+            kind: self.kind,
+            name: self.name.to_string(),
+            size: self.size,
+            date: self.date,
+            date_str: self.date_str.as_str().to_string(),
+            target: self.target.map(str::to_string),
+            sticky: self.sticky,
+            setuid: self.setuid,
+            setgid: self.setgid,
+            mandatory_locking: self.mandatory_locking,
+            permissions: self.permissions,
+            link_count: self.link_count,
+            acl: self.acl,
+            xattr: self.xattr,
+            selinux: self.selinux,
+            owner: self.owner.map(str::to_string),
+            group: self.group.map(str::to_string),
+            ownership: self.ownership.map(str::to_string),
+            pointer: self.pointer.as_ref().map(|v| v.to_string()),
+            device: self.device,
+            approximate_size: self.approximate_size,
+        }
+    }
+}
+
+impl FtpEntryInfo for FtpEntryUnixRef<'_> {
+    fn kind(&self) -> FtpEntryKind {
+        self.kind
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn size(&self) -> u64 {
+        self.size
+    }
+
+    fn date(&self) -> Option<FtpEntryDate> {
+        Some(self.date)
+    }
+
+    fn date_str(&self) -> &str {
+        self.date_str.as_str()
+    }
+}
+
 impl TryFrom<&str> for FtpEntryUnix {
-    type Error = ();
+    type Error = FtpEntryError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        if let Some(caps) = RELIST.captures(&value) {
-            let kind: FtpEntryKind = caps
-                .name("type")
-                .ok_or("this is not a list unix format")
-                .map_or_else(|_| Err(()), |v| v.as_str().try_into().map_err(|_| ()))?;
-
-            let (sticky, permissions) = caps
-                .name("permission")
-                .ok_or("this is not a list unix format")
-                .map_or_else(
-                    |_| Err(()),
-                    |v| {
-                        let mut permission = v.as_str().to_string();
-                        Ok((
-                            match permission.chars().last() {
-                                Some(t) if t == 't' || t == 'T' => {
-                                    permission.pop();
-                                    permission.push(if t == 't' { 'x' } else { '-' });
-                                    true
-                                }
-                                _ => false,
-                            },
-                            permission,
-                        ))
-                    },
-                )?;
-
-            let acl = caps.name("acl").map(|v| v.as_str() == "+").unwrap_or(false);
-            let owner = caps.name("owner").unwrap().as_str().to_string();
-            let group = caps.name("group").unwrap().as_str().to_string();
-
-            let (size, pointer) = caps.name("size").map_or((0, None), |v| {
-                if v.as_str().chars().any(|c| c == ',') {
-                    (
-                        0,
-                        Some(v.as_str().chars().filter(|c| !c.is_whitespace()).collect()),
-                    )
-                } else {
-                    (v.as_str().parse().unwrap_or(0), None)
+        FtpEntryUnixRef::try_from(value).map(|entry| entry.to_owned())
+    }
+}
+
+impl<'a> TryFrom<&'a str> for FtpEntryUnixRef<'a> {
+    type Error = FtpEntryError;
+
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        if let Some(caps) = RELIST.captures(value).or_else(|| RESINGLE.captures(value)) {
+            let name = caps.name("name").unwrap().as_str();
+
+            return FtpEntryUnixRef::from_captures(value, &caps, owners_of(&caps), name);
+        }
+
+        let err = diagnose(value, FtpEntryFormat::Unix, &REPREFIXES);
+        let single = diagnose(value, FtpEntryFormat::Unix, &RESINGLEPREFIXES);
+        Err(if single.offset() > err.offset() {
+            single
+        } else {
+            err
+        })
+    }
+}
+
+impl<'a> FtpEntryUnixRef<'a> {
+    /// Parses the line of a listing with the given columns,
+    /// see [`FtpListing::parse_aligned`](crate::FtpListing::parse_aligned).
+    /// Falls back to [`TryFrom`] if the line does not fit the columns.
+    pub(crate) fn parse_aligned(
+        value: &'a str,
+        columns: &FtpEntryUnixColumns,
+    ) -> Result<Self, FtpEntryError> {
+        let caps = match REALIGNED.captures(value) {
+            Some(caps) => caps,
+            None => return FtpEntryUnixRef::try_from(value),
+        };
+
+        let owners = caps.name("owners").unwrap();
+        let (owner, group, ownership) = match columns.group {
+            Some(column) => match column
+                .checked_sub(value[..owners.start()].chars().count())
+                .and_then(|offset| owners.as_str().char_indices().nth(offset))
+                .map(|(idx, _)| owners.as_str().split_at(idx))
+            {
+                Some((owner, group)) if !owner.trim().is_empty() => {
+                    (Some(owner.trim_end()), Some(group.trim_end()), None)
                 }
-            });
+                _ => return FtpEntryUnixRef::try_from(value),
+            },
+            // Without the group column the owner and the group are told apart
+            // the same way as in a single line, only the name stays aligned.
+            None => match RELIST.captures(value).or_else(|| RESINGLE.captures(value)) {
+                Some(line) => owners_of(&line),
+                None => return FtpEntryUnixRef::try_from(value),
+            },
+        };
+
+        let rest = caps.name("rest").unwrap().as_str();
+        let name = match rest
+            .char_indices()
+            .take(columns.name_gap)
+            .find(|(_, c)| !c.is_whitespace())
+        {
+            Some((idx, _)) => &rest[idx..],
+            None => rest
+                .char_indices()
+                .nth(columns.name_gap)
+                .map_or("", |(idx, _)| &rest[idx..]),
+        };
+        if name.is_empty() {
+            return FtpEntryUnixRef::try_from(value);
+        }
+
+        FtpEntryUnixRef::from_captures(value, &caps, (owner, group, ownership), name)
+    }
+
+    fn from_captures(
+        value: &'a str,
+        caps: &Captures<'a>,
+        (owner, group, ownership): Owners<'a>,
+        name: &'a str,
+    ) -> Result<Self, FtpEntryError> {
+        let error = |field, offset| {
+            FtpEntryError::new(Some(FtpEntryFormat::Unix), field, offset).with_progress(value.len())
+        };
 
-            let date_str = caps
-                .name("timestamp")
-                .unwrap()
-                .as_str()
-                .split_whitespace()
-                .collect::<Vec<_>>()
-                .join(" ");
-
-            // let date = None
-            //     .or_else(|| {
-            //         let date = vec![
-            //             caps.name("month1"),
-            //             caps.name("date1"),
-            //             caps.name("hour"),
-            //             caps.name("minute"),
-            //         ];
-
-            //         let date = if date.iter().any(|m| m.is_none()) {
-            //             return None;
-            //         } else {
-            //             date.iter().map(|m| m.unwrap().as_str()).collect::<Vec<_>>()
-            //         };
-
-            //         let now = ::chrono::Utc::now();
-            //         let year = format!("{}", now.format("%Y"));
-
-            //         let date = format!("{}-{}-{}T{}:{}", year, date[0], date[1], date[2], date[3]);
-            //         let date = match NaiveDateTime::parse_from_str(&date, "%Y-%b-%_dT%_H:%_M") {
-            //             Ok(mut date) => {
-            //                 // If the date is in the past but no more than 6 months old, year
-            //                 // isn't displayed and doesn't have to be the current year.
-            //                 //
-            //                 // If the date is in the future (less than an hour from now), year
-            //                 // isn't displayed and doesn't have to be the current year.
-            //                 // That second case is much more rare than the first and less annoying.
-            //                 // It's impossible to fix without knowing about the server's timezone,
-            //                 // so we just don't do anything about it.
-            //                 //
-            //                 // If we're here with a time that is more than 28 hours into the
-            //                 // future (1 hour + maximum timezone offset which is 27 hours),
-            //                 // there is a problem -- we should be in the second conditional block
-            //                 if date.timestamp() - now.timestamp() > 100_800_000 {
-            //                     date = date.with_year(date.year() - 1).unwrap();
-            //                 }
-
-            //                 // If we're here with a time that is more than 6 months old, there's
-            //                 // a problem as well.
-            //                 // Maybe local & remote servers aren't on the same timezone (with remote
-            //                 // ahead of local)
-            //                 // For instance, remote is in 2014 while local is still in 2013. In
-            //                 // this case, a date like 01/01/13 02:23 could be detected instead of
-            //                 // 01/01/14 02:23
-            //                 // Our trigger point will be 3600*24*31*6 (since we already use 31
-            //                 // as an upper bound, no need to add the 27 hours timezone offset)
-            //                 if now.timestamp() - date.timestamp() > 16_070_400_000 {
-            //                     date = date.with_year(date.year() - 1).unwrap();
-            //                 }
-
-            //                 Some(date)
-            //             }
-            //             Err(_) => None,
-            //         };
-
-            //         date
-            //     })
-            //     .or_else(|| {
-            //         let date = vec![caps.name("year"), caps.name("month2"), caps.name("date2")];
-
-            //         let date = if date.iter().any(|m| m.is_none()) {
-            //             return None;
-            //         } else {
-            //             date.iter().map(|m| m.unwrap().as_str()).collect::<Vec<_>>()
-            //         };
-
-            //         let date = format!("{}-{}-{}", date[0], date[1], date[2]);
-            //         NaiveDate::parse_from_str(&date, "%Y-%b-%_d")
-            //             .map(|date| date.and_hms(0, 0, 0))
-            //             .ok()
-            //     });
-
-            // let date = match date {
-            //     Some(date) => date,
-            //     _ => return Err(()),
-            // };
-
-            let (name, target) = {
-                let name = caps.name("name").unwrap().as_str();
-                if kind == FtpEntryKind::Symlink {
-                    let mut s1 = name.split(" -> ");
-                    (
-                        s1.next().unwrap().to_string(),
-                        s1.next().map(|v| v.to_string()),
-                    )
-                } else {
-                    (name.to_string(), None)
+        let kind: FtpEntryKind = caps["type"]
+            .try_into()
+            .map_err(|_| error(FtpEntryField::Kind, 0))?;
+
+        let permissions = caps.name("permission").unwrap();
+        let permissions = FtpEntryPermissions::try_from(permissions.as_str()).map_err(|err| {
+            error(
+                FtpEntryField::Permissions,
+                permissions.start() + err.offset(),
+            )
+        })?;
+        let sticky = permissions.sticky();
+        let setuid = permissions.setuid();
+        let setgid = permissions.setgid();
+        let mandatory_locking = permissions.mandatory_locking();
+
+        let link_count = caps.name("inodes").unwrap();
+        let link_count = link_count
+            .as_str()
+            .parse()
+            .map_err(|_| error(FtpEntryField::LinkCount, link_count.start()))?;
+
+        let marker = caps.name("marker").map(|v| v.as_str());
+        let acl = marker == Some("+");
+        let xattr = marker == Some("@");
+        let selinux = marker == Some(".");
+
+        let approximate_size = caps.name("hsize").is_some();
+        let (size, pointer, device) = {
+            let v = caps.name("size").unwrap();
+            let (size, pointer, device) = if approximate_size {
+                (parse_human_size(caps), None, None)
+            } else if caps.name("major").is_some() {
+                // Only devices have the device number in place of the size.
+                let device = match kind {
+                    FtpEntryKind::BlockDevice | FtpEntryKind::CharacterDevice => parse_device(caps),
+                    _ => None,
                 }
+                .ok_or_else(|| error(FtpEntryField::Size, v.start()))?;
+                (Some(0), Some(without_whitespace(v.as_str())), Some(device))
+            } else {
+                (v.as_str().parse().ok(), None, None)
             };
-
-            return Ok(Self {
-                kind,
-                name,
-                target,
-                sticky,
-                permissions: FtpEntryPermissions(permissions),
-                acl,
-                owner,
-                group,
-                size,
+            (
+                size.ok_or_else(|| error(FtpEntryField::Size, v.start()))?,
                 pointer,
-                date_str,
-            });
-        }
+                device,
+            )
+        };
+
+        let timestamp = caps.name("timestamp").unwrap();
+        let date = parse_month_date(caps)
+            .ok_or_else(|| error(FtpEntryField::Timestamp, timestamp.start()))?;
+        let date_str = DateStr::collapse_whitespace(timestamp.as_str())
+            .map_err(|_| error(FtpEntryField::Timestamp, timestamp.start()))?;
+
+        let (name, target) = {
+            if kind == FtpEntryKind::Symlink {
+                let mut s1 = name.split(" -> ");
+                (s1.next().unwrap(), s1.next())
+            } else {
+                (name, None)
+            }
+        };
+
+        Ok(Self {
+            kind,
+            name,
+            target,
+            sticky,
+            setuid,
+            setgid,
+            mandatory_locking,
+            permissions,
+            link_count,
+            acl,
+            xattr,
+            selinux,
+            owner,
+            group,
+            ownership,
+            size,
+            pointer,
+            device,
+            approximate_size,
+            date,
+            date_str,
+        })
+    }
+}
+
+/// The owner, the group and the single ownership column of the line.
+type Owners<'a> = (Option<&'a str>, Option<&'a str>, Option<&'a str>);
+
+/// Returns the columns matched by either [`FIELDS`] or [`SINGLE_FIELDS`].
+fn owners_of<'a>(caps: &Captures<'a>) -> Owners<'a> {
+    let column = |s| caps.name(s).map(|v| v.as_str());
+    (column("owner"), column("group"), column("ownership"))
+}
+
+/// Removes whitespace from `8,   0` form of the device number,
+/// borrowing the value if there is none.
+fn without_whitespace(value: &str) -> Cow<'_, str> {
+    if value.contains(char::is_whitespace) {
+        Cow::Owned(value.chars().filter(|c| !c.is_whitespace()).collect())
+    } else {
+        Cow::Borrowed(value)
+    }
+}
+
+/// Parses `8,   0`, `4, 0x00030000` or `8,` form of the device number.
+fn parse_device(caps: &Captures) -> Option<FtpEntryUnixDevice> {
+    let minor = match caps.name("minor").map(|v| v.as_str()) {
+        Some(minor) => Some(match minor.strip_prefix("0x") {
+            Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+            None => minor.parse().ok()?,
+        }),
+        None => None,
+    };
+    Some(FtpEntryUnixDevice {
+        major: caps.name("major")?.as_str().parse().ok()?,
+        minor,
+    })
+}
+
+/// Parses human-readable size like `4.2M`. A lowercase `k` and units like
+/// `MB` are powers of 1000 (`ls --si`), while bare uppercase units
+/// and units like `MiB` are powers of 1024 (`ls -h`).
+/// Returns `None` if the size does not fit into `u64`.
+fn parse_human_size(caps: &Captures) -> Option<u64> {
+    let number: f64 = caps
+        .name("number")?
+        .as_str()
+        .replace(',', ".")
+        .parse()
+        .ok()?;
+    let unit = caps.name("unit")?.as_str();
+    let hsize = caps.name("hsize")?.as_str();
+
+    let base: f64 = if caps.name("binary").is_some() {
+        1024.0
+    } else if unit == "k" || hsize.ends_with('B') {
+        1000.0
+    } else {
+        1024.0
+    };
+    let exponent = "KMGTPE".find(&unit.to_ascii_uppercase())? as i32 + 1;
 
-        Err(())
+    let size = (number * base.powi(exponent)).round();
+    if size < u64::MAX as f64 {
+        Some(size as u64)
+    } else {
+        None
     }
 }
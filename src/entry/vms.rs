@@ -0,0 +1,231 @@
+use std::convert::TryFrom;
+
+use ::regex::{Captures, Regex};
+
+use super::*;
+
+/// Patterns of the fields in the order they appear in the line.
+const FIELDS: [(FtpEntryField, &str); 5] = [
+    (FtpEntryField::Name, r"^(?P<name>[^;\s]+);(?P<version>\d+)"),
+    (
+        FtpEntryField::Size,
+        r"\s+(?P<used>\d+)(?:/(?P<allocated>\d+))?",
+    ),
+    (
+        FtpEntryField::Timestamp,
+        r"
+        \s+(?P<timestamp>(?P<day>\d{1,2})-(?P<month>[A-Za-z]{3})-(?P<year>\d{4})\s+
+            (?P<hour>\d{1,2}):(?P<minute>\d{2})(?::(?P<second>\d{2})(?:\.\d+)?)?)",
+    ),
+    (FtpEntryField::Owner, r"\s+\[(?P<owner>[^\]]+)\]"),
+    (
+        FtpEntryField::Permissions,
+        r"\s+\((?P<protection>[RWED]*,[RWED]*,[RWED]*,[RWED]*)\)\s*$",
+    ),
+];
+
+lazy_static! {
+    static ref RELIST: Regex = whole(&FIELDS);
+    static ref REPREFIXES: Vec<(FtpEntryField, Regex)> = prefixes(&FIELDS);
+}
+
+/// Owner of the VMS entry, the user identification code (UIC).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FtpEntryVmsUic {
+    /// Group part of `[GROUP,MEMBER]`, `None` for the `[MEMBER]` form.
+    pub group: Option<String>,
+    /// Member part of `[GROUP,MEMBER]`.
+    pub member: String,
+}
+
+/// Access granted to one class of users of the VMS entry.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct FtpEntryVmsAccess {
+    pub read: bool,
+    pub write: bool,
+    pub execute: bool,
+    pub delete: bool,
+}
+
+impl From<&str> for FtpEntryVmsAccess {
+    fn from(value: &str) -> Self {
+        Self {
+            read: value.contains('R'),
+            write: value.contains('W'),
+            execute: value.contains('E'),
+            delete: value.contains('D'),
+        }
+    }
+}
+
+/// Protection mask of the VMS entry, e.g. `(RWED,RWED,RE,)`.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct FtpEntryVmsProtection {
+    pub system: FtpEntryVmsAccess,
+    pub owner: FtpEntryVmsAccess,
+    pub group: FtpEntryVmsAccess,
+    pub world: FtpEntryVmsAccess,
+}
+
+/// Represents entry from VMS / OpenVMS FTP server:
+/// ```text
+/// FILE.TXT;3   12/24   1-JAN-2020 10:00:00  [GROUP,OWNER]  (RWED,RWED,RE,)
+/// ```
+#[derive(Debug)]
+pub struct FtpEntryVms {
+    kind: FtpEntryKind,
+    name: String,
+    size: u64,
+    date: FtpEntryDate,
+    date_str: String,
+    /// Version of the file, the number after `;`.
+    pub version: u32,
+    /// Number of 512-byte blocks used by the file.
+    pub blocks_used: u64,
+    /// Number of 512-byte blocks allocated for the file, if the server sent it.
+    pub blocks_allocated: Option<u64>,
+    /// The owner of the entry.
+    pub owner: FtpEntryVmsUic,
+    /// The protection mask of the entry.
+    pub protection: FtpEntryVmsProtection,
+}
+
+impl FtpEntryVms {
+    /// Represents parsed string as entry of a VMS FTP server.
+    pub fn new(string: &str) -> Option<Self> {
+        FtpEntryVms::try_from(string).ok()
+    }
+}
+
+impl FtpEntryInfo for FtpEntryVms {
+    fn kind(&self) -> FtpEntryKind {
+        self.kind
+    }
+
+    /// Returns name of the entry without version.
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns size of the entry in bytes, computed from the used blocks.
+    fn size(&self) -> u64 {
+        self.size
+    }
+
+    fn date(&self) -> Option<FtpEntryDate> {
+        Some(self.date)
+    }
+
+    fn date_str(&self) -> &str {
+        &self.date_str
+    }
+}
+
+impl TryFrom<&str> for FtpEntryVms {
+    type Error = FtpEntryError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let error = |field, offset| {
+            FtpEntryError::new(Some(FtpEntryFormat::Vms), field, offset).with_progress(value.len())
+        };
+
+        if let Some(caps) = RELIST.captures(value) {
+            let as_str = |s| caps.name(s).unwrap().as_str();
+
+            let name = as_str("name");
+            let kind = if name.to_ascii_uppercase().ends_with(".DIR") {
+                FtpEntryKind::Directory
+            } else {
+                FtpEntryKind::File
+            };
+
+            let version = as_str("version")
+                .parse()
+                .map_err(|_| error(FtpEntryField::Name, 0))?;
+
+            let blocks_used: u64 = as_str("used")
+                .parse()
+                .map_err(|_| error(FtpEntryField::Size, caps.name("used").unwrap().start()))?;
+            let blocks_allocated = caps
+                .name("allocated")
+                .map(|v| {
+                    v.as_str()
+                        .parse()
+                        .map_err(|_| error(FtpEntryField::Size, v.start()))
+                })
+                .transpose()?;
+
+            let timestamp = caps.name("timestamp").unwrap();
+            let date = parse_date(&caps)
+                .ok_or_else(|| error(FtpEntryField::Timestamp, timestamp.start()))?;
+            let date_str = timestamp
+                .as_str()
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ");
+
+            let owner = match as_str("owner").split_once(',') {
+                Some((group, member)) => FtpEntryVmsUic {
+                    group: Some(group.to_string()),
+                    member: member.to_string(),
+                },
+                None => FtpEntryVmsUic {
+                    group: None,
+                    member: as_str("owner").to_string(),
+                },
+            };
+
+            let protection = {
+                let mut classes = as_str("protection").split(',').map(FtpEntryVmsAccess::from);
+                FtpEntryVmsProtection {
+                    system: classes.next().unwrap(),
+                    owner: classes.next().unwrap(),
+                    group: classes.next().unwrap(),
+                    world: classes.next().unwrap(),
+                }
+            };
+
+            return Ok(Self {
+                kind,
+                name: name.to_string(),
+                size: blocks_used.checked_mul(512).ok_or_else(|| {
+                    error(FtpEntryField::Size, caps.name("used").unwrap().start())
+                })?,
+                date,
+                date_str,
+                version,
+                blocks_used,
+                blocks_allocated,
+                owner,
+                protection,
+            });
+        }
+
+        Err(diagnose(value, FtpEntryFormat::Vms, &REPREFIXES))
+    }
+}
+
+/// Parses `1-JAN-2020 10:00:00` form of the timestamp.
+fn parse_date(caps: &Captures) -> Option<FtpEntryDate> {
+    let number = |s| caps.name(s).and_then(|v| v.as_str().parse::<u8>().ok());
+
+    let year = caps.name("year")?.as_str().parse().ok()?;
+    let month = month_from_abbr(caps.name("month")?.as_str())?;
+    match caps.name("second") {
+        Some(_) => FtpEntryDate::from_ymd_hms(
+            year,
+            month,
+            number("day")?,
+            number("hour")?,
+            number("minute")?,
+            number("second")?,
+        ),
+        None => FtpEntryDate::from_ymd_hm(
+            year,
+            month,
+            number("day")?,
+            number("hour")?,
+            number("minute")?,
+        ),
+    }
+}
@@ -0,0 +1,153 @@
+use std::fmt::{self, Display};
+
+/// Format of the `LIST` response line.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FtpEntryFormat {
+    Unix,
+    Msdos,
+    Mlsd,
+    Eplf,
+    Vms,
+    Mvs,
+    As400,
+    Guardian,
+    Netware,
+}
+
+impl Display for FtpEntryFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            FtpEntryFormat::Unix => "unix",
+            FtpEntryFormat::Msdos => "msdos",
+            FtpEntryFormat::Mlsd => "mlsd",
+            FtpEntryFormat::Eplf => "eplf",
+            FtpEntryFormat::Vms => "vms",
+            FtpEntryFormat::Mvs => "mvs",
+            FtpEntryFormat::As400 => "as400",
+            FtpEntryFormat::Guardian => "guardian",
+            FtpEntryFormat::Netware => "netware",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+/// Field of the `LIST` response line.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FtpEntryField {
+    Kind,
+    Permissions,
+    LinkCount,
+    Owner,
+    Group,
+    Size,
+    Timestamp,
+    Name,
+    /// A fact of the `MLSD` or EPLF entry.
+    Fact,
+    /// Volume and unit of the MVS dataset.
+    Volume,
+    /// Record format, record length, block size and organization of the MVS dataset.
+    RecordFormat,
+    /// `VV.MM` version of the MVS member.
+    Version,
+}
+
+impl Display for FtpEntryField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            FtpEntryField::Kind => "kind",
+            FtpEntryField::Permissions => "permissions",
+            FtpEntryField::LinkCount => "link count",
+            FtpEntryField::Owner => "owner",
+            FtpEntryField::Group => "group",
+            FtpEntryField::Size => "size",
+            FtpEntryField::Timestamp => "timestamp",
+            FtpEntryField::Name => "name",
+            FtpEntryField::Fact => "fact",
+            FtpEntryField::Volume => "volume",
+            FtpEntryField::RecordFormat => "record format",
+            FtpEntryField::Version => "version",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+/// Describes why a string could not be parsed as an ftp entry.
+/// ```rust
+/// # use std::convert::TryFrom;
+/// # use ftp_cmd_list_parse::{FtpEntryField, FtpEntryFormat, FtpEntryUnix};
+/// let err = FtpEntryUnix::try_from("drwxr-xr-x  10 root   root    4096 Dez 21  2012 usr").unwrap_err();
+///
+/// assert_eq!(err.format(), Some(FtpEntryFormat::Unix));
+/// assert_eq!(err.field(), FtpEntryField::Timestamp);
+/// assert_eq!(err.offset(), 35);
+/// assert_eq!(err.to_string(), "unix entry: invalid timestamp at byte 35");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FtpEntryError {
+    format: Option<FtpEntryFormat>,
+    field: FtpEntryField,
+    offset: usize,
+    /// How far into the string the parser got before it failed.
+    /// Used to pick the most relevant error out of several formats.
+    progress: usize,
+}
+
+impl FtpEntryError {
+    pub(crate) fn new(format: Option<FtpEntryFormat>, field: FtpEntryField, offset: usize) -> Self {
+        Self {
+            format,
+            field,
+            offset,
+            progress: offset,
+        }
+    }
+
+    /// Marks that the whole string matched the format, but `field` has an invalid value.
+    pub(crate) fn with_progress(self, progress: usize) -> Self {
+        Self { progress, ..self }
+    }
+
+    /// Moves the offset and the progress, e.g. from a decoded line to its raw bytes.
+    pub(crate) fn map_offset(self, f: impl Fn(usize) -> usize) -> Self {
+        Self {
+            offset: f(self.offset),
+            progress: f(self.progress),
+            ..self
+        }
+    }
+
+    pub(crate) fn progress(&self) -> usize {
+        self.progress
+    }
+
+    /// Returns the format that was tried, or `None` if the error
+    /// does not relate to a particular format.
+    pub fn format(&self) -> Option<FtpEntryFormat> {
+        self.format
+    }
+
+    /// Returns the field that failed to parse.
+    pub fn field(&self) -> FtpEntryField {
+        self.field
+    }
+
+    /// Returns the byte offset in the parsed string where the field was expected.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl Display for FtpEntryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(format) = self.format {
+            write!(f, "{} entry: ", format)?;
+        }
+
+        write!(f, "invalid {} at byte {}", self.field, self.offset)
+    }
+}
+
+impl std::error::Error for FtpEntryError {}
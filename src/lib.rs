@@ -16,6 +16,54 @@
 //! 08-22-2018  02:05PM       <DIR>          wwwroot
 //! 08-22-18  12:59PM                99710 logo.jpg
 //! 08-22-18  03:01AM                99710 music.mp3
+//! 2018-08-22  14:05              1024 web.config
+//! 08-22-18  02:05PM    <JUNCTION>     Documents and Settings [C:\Users]
+//! ```
+//!
+//! * MLSD (RFC 3659):
+//! ```text
+//! type=dir;modify=20120921101300;perm=flcdmpe; pub
+//! type=file;size=7045120;modify=20120902101300;perm=adfrw; music.mp3
+//! ```
+//!
+//! * EPLF, the name follows a tab character:
+//! ```text
+//! +i8388621.48594,m825718503,r,s280,\tdjb.html
+//! +i8388621.50690,m824255907,/,\t514
+//! ```
+//!
+//! * VMS / OpenVMS:
+//! ```text
+//! FILE.TXT;3   12/24   1-JAN-2020 10:00:00  [GROUP,OWNER]  (RWED,RWED,RE,)
+//! SUBDIR.DIR;1          1  23-SEP-2019 08:15  [SYSTEM]  (RWE,RWE,RE,RE)
+//! ```
+//!
+//! * IBM z/OS MVS datasets and PDS members:
+//! ```text
+//! Volume Unit    Referred Ext Used Recfm Lrecl BlkSz Dsorg Dsname
+//! WYOSPT 3420   2003/05/21  1  200  FB      80  8053  PS  BPL.CICS.ONLINE
+//! Migrated                                                OLD.DATASET
+//!  Name     VV.MM   Created       Changed      Size  Init   Mod   Id
+//! MEMBER1   01.01 2002/09/10 2002/09/10 16:09    15    15     0 USERID
+//! ```
+//!
+//! * IBM AS/400 (OS/400, IBM i):
+//! ```text
+//! QSYS           77824 02/23/00 15:09:55 *DIR       RCHFOLDER/
+//! QPGMR          12288 06/08/20 17:49:31 *FILE      QGPL/QCLSRC.FILE
+//!                                        *MEM       QGPL/QCLSRC.FILE/START.MBR
+//! ```
+//!
+//! * Tandem / HP NonStop Guardian:
+//! ```text
+//! File         Code             EOF  Last Modification    Owner  RWEP
+//! EDITFILE       101         8192  12-Jul-99 11:47:36  255, 0 "oooo"
+//! ```
+//!
+//! * NetWare / Novell:
+//! ```text
+//! d [RWCEAFMS] owner                512 Mar 03 14:04 dirname
+//! - [R----F--] owner               1024 Dec 21  2012 file.txt
 //! ```
 //!
 //! ## Examples:
@@ -44,8 +92,8 @@
 //! if let Ok(ftp_entry) = FtpEntry::try_from(ftp_response) {
 //!     match ftp_entry.try_to_unix_type() {
 //!         Ok(ftp_entry_unix) => { // `FtpEntryUnix` type
-//!             println!("Owner: {}", ftp_entry_unix.owner); // "root"
-//!             println!("Group: {}", ftp_entry_unix.group); // "root"
+//!             println!("Owner: {:?}", ftp_entry_unix.owner); // Some("root")
+//!             println!("Group: {:?}", ftp_entry_unix.group); // Some("root")
 //!             println!("Permissions: {}", ftp_entry_unix.permissions.as_str()); // "rwxr-xr-x"
 //!         },
 //!         Err(ftp_entry) => { // `FtpEntry` type
@@ -69,14 +117,39 @@
 //!
 //! let ftp_response: &'static str = "drwxr-xr-x  10 root   root    4096 Dec 21  2012 usr";
 //! if let Some(ftp_entry_unix) = FtpEntryUnix::new(ftp_response) {
-//!     println!("Owner: {}", ftp_entry_unix.owner); // "root"
-//!     println!("Group: {}", ftp_entry_unix.group); // "root"
+//!     println!("Owner: {:?}", ftp_entry_unix.owner); // Some("root")
+//!     println!("Group: {:?}", ftp_entry_unix.group); // Some("root")
 //!     println!("Permissions: {}", ftp_entry_unix.permissions); // "rwxr-xr-x"
 //! }
 //! ```
+//!
+//! To parse the whole response of `LIST` command at once, use [`FtpListing`]:
+//!
+//! ```rust
+//! use ftp_cmd_list_parse::FtpListing;
+//!
+//! let ftp_response = "total 8\r\ndrwxr-xr-x  10 root   root    4096 Dec 21  2012 usr\r\n";
+//! let listing = FtpListing::parse(ftp_response);
+//! for ftp_entry in &listing.entries {
+//!     println!("{}", ftp_entry.name()); // "usr"
+//! }
+//! for line in &listing.unparsed {
+//!     println!("Line {} is not valid: {}", line.line_number, line.line);
+//! }
+//! ```
 
 #[macro_use]
 extern crate lazy_static;
 
+mod date;
+pub use date::*;
 mod entry;
 pub use entry::*;
+mod error;
+pub use error::*;
+mod listing;
+pub use listing::*;
+mod permissions;
+pub use permissions::*;
+mod raw;
+pub use raw::*;
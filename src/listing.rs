@@ -0,0 +1,209 @@
+use std::{borrow::Cow, collections::HashMap};
+
+use ::regex::Regex;
+
+use crate::{
+    FtpEntry, FtpEntryDateOrder, FtpEntryError, FtpEntryGuardian, FtpEntryInfo, FtpEntryKind,
+    FtpEntryMvsDataset, FtpEntryMvsMember, FtpEntryUnix, FtpEntryUnixColumns, FtpEntryUnixRef,
+};
+
+lazy_static! {
+    static ref RETOTAL: Regex = Regex::new(r"(?i)^total\s+\d+\s*$").unwrap();
+    static ref REVMSHEADER: Regex =
+        Regex::new(r"^(Directory\s+\S+|(Grand total|Total) of \d+ files?.*)$").unwrap();
+    static ref REVMSNAME: Regex = Regex::new(r"^[^;\s]+;\d+\s*$").unwrap();
+}
+
+/// Represents a line of the listing that could not be parsed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FtpUnparsedLine {
+    /// Number of the line in the listing, starting from 1.
+    pub line_number: usize,
+    /// The line itself without line ending.
+    pub line: String,
+    /// Describes why the line could not be parsed.
+    pub error: FtpEntryError,
+}
+
+/// Represents a whole response of the `LIST` command.
+#[derive(Debug)]
+pub struct FtpListing {
+    /// Entries in the order they were sent by the server.
+    pub entries: Vec<FtpEntry>,
+    /// Lines that look like neither an entry nor a header.
+    pub unparsed: Vec<FtpUnparsedLine>,
+}
+
+impl FtpListing {
+    /// Parses every line of the `LIST` response.
+    ///
+    /// Both `CRLF` and `LF` line endings are accepted. Blank lines, the
+    /// `total N` line that Unix servers put before entries and the
+    /// directory header and summary lines of VMS servers are skipped.
+    /// VMS entries with a long name wrapped to the next line are joined.
+    /// The column headers of MVS and Guardian listings are skipped too, and after the
+    /// header of a member listing, lines with a bare name are read as members.
+    /// ```rust
+    /// # use ftp_cmd_list_parse::FtpListing;
+    /// let response = "total 8\r\n\
+    ///     drwxr-xr-x  10 root   root    4096 Dec 21  2012 usr\r\n\
+    ///     garbage\r\n";
+    ///
+    /// let listing = FtpListing::parse(response);
+    /// assert_eq!(listing.entries.len(), 1);
+    /// assert_eq!(listing.entries[0].name(), "usr");
+    /// assert_eq!(listing.unparsed[0].line_number, 3);
+    /// ```
+    pub fn parse(listing: &str) -> Self {
+        FtpListing::parse_with_date_order(listing, FtpEntryDateOrder::default())
+    }
+
+    /// Parses every line of the `LIST` response like [`parse`](#method.parse)
+    /// does, reading numeric dates of MSDOS-like entries in the given order.
+    /// The order is the same for every line of one response.
+    pub fn parse_with_date_order(listing: &str, order: FtpEntryDateOrder) -> Self {
+        FtpListing::parse_lines(listing, order, None)
+    }
+
+    /// Parses every line of the `LIST` response like [`parse`](#method.parse)
+    /// does, but reads Unix entries by columns shared by all their lines.
+    ///
+    /// `ls -l` pads the owner and the group to the same width in every line
+    /// and puts one space between the date and the name, so the columns let
+    /// owners and groups with spaces be split and names with leading or
+    /// trailing whitespace be kept as the server sent them. Lines that do
+    /// not fit the columns, and listings where the group column cannot be
+    /// told, are parsed as usual.
+    /// ```rust
+    /// # use ftp_cmd_list_parse::FtpListing;
+    /// let response = "-rw-r--r--   1 john doe  staff      1024 Sep 02  2012   indented.txt\n\
+    ///     -rw-r--r--   1 root      wheel      2048 Sep 02  2012 plain.txt\n";
+    ///
+    /// let listing = FtpListing::parse_aligned(response);
+    /// assert_eq!(listing.entries[0].name(), "  indented.txt");
+    /// assert_eq!(listing.entries[1].name(), "plain.txt");
+    /// ```
+    pub fn parse_aligned(listing: &str) -> Self {
+        let columns = FtpEntryUnixColumns::infer(listing.lines());
+        FtpListing::parse_lines(listing, FtpEntryDateOrder::default(), columns)
+    }
+
+    fn parse_lines(
+        listing: &str,
+        order: FtpEntryDateOrder,
+        columns: Option<FtpEntryUnixColumns>,
+    ) -> Self {
+        let mut entries = vec![];
+        let mut unparsed = vec![];
+
+        let mut mvs_members = false;
+        let mut lines = listing.lines().enumerate().peekable();
+        while let Some((idx, line)) = lines.next() {
+            if line.trim().is_empty()
+                || RETOTAL.is_match(line)
+                || REVMSHEADER.is_match(line)
+                || FtpEntryGuardian::is_header(line)
+            {
+                continue;
+            }
+
+            if FtpEntryMvsDataset::is_header(line) {
+                mvs_members = false;
+                continue;
+            }
+
+            if FtpEntryMvsMember::is_header(line) {
+                mvs_members = true;
+                continue;
+            }
+
+            let line = match lines.peek() {
+                Some((_, next))
+                    if REVMSNAME.is_match(line) && next.starts_with(char::is_whitespace) =>
+                {
+                    let joined = format!("{} {}", line.trim_end(), next.trim_start());
+                    lines.next();
+                    Cow::Owned(joined)
+                }
+                _ => Cow::Borrowed(line),
+            };
+
+            let aligned = columns.as_ref().and_then(|columns| {
+                FtpEntryUnixRef::parse_aligned(&line, columns)
+                    .ok()
+                    .map(|entry| FtpEntry::Unix(entry.to_owned()))
+            });
+
+            let entry = match aligned {
+                Some(entry) => Ok(entry),
+                None => FtpEntry::parse_with_date_order(line.as_ref(), order),
+            }
+            .or_else(|error| match FtpEntryMvsMember::name_only(&line) {
+                Some(member) if mvs_members => Ok(FtpEntry::MvsMember(member)),
+                _ => Err(error),
+            });
+
+            match entry {
+                Ok(entry) => entries.push(entry),
+                Err(error) => unparsed.push(FtpUnparsedLine {
+                    line_number: idx + 1,
+                    line: line.into_owned(),
+                    error,
+                }),
+            }
+        }
+
+        Self { entries, unparsed }
+    }
+
+    /// Groups Unix files that may be hard links to each other: files with
+    /// more than one link and the same size and modification date.
+    ///
+    /// The listing tells nothing about inodes, so files in a group are only
+    /// candidates, and links to them from other directories are not seen.
+    /// Groups are in the order of their first entry, files without a
+    /// possible sibling are left out.
+    /// ```rust
+    /// # use ftp_cmd_list_parse::FtpListing;
+    /// let response = "-rw-r--r--   2 root   root    1024 Dec 21  2012 a.txt\n\
+    ///     -rw-r--r--   2 root   root    1024 Dec 21  2012 b.txt\n\
+    ///     -rw-r--r--   1 root   root    1024 Dec 21  2012 c.txt\n";
+    ///
+    /// let listing = FtpListing::parse(response);
+    /// let groups = listing.hard_link_candidates();
+    /// assert_eq!(groups.len(), 1);
+    /// assert_eq!(groups[0].len(), 2);
+    /// ```
+    pub fn hard_link_candidates(&self) -> Vec<Vec<&FtpEntryUnix>> {
+        let mut groups: Vec<Vec<&FtpEntryUnix>> = vec![];
+        let mut indices = HashMap::new();
+
+        for entry in &self.entries {
+            let entry = match entry {
+                FtpEntry::Unix(entry)
+                    if entry.kind() == FtpEntryKind::File && entry.link_count > 1 =>
+                {
+                    entry
+                }
+                _ => continue,
+            };
+
+            let idx = *indices
+                .entry((entry.size(), entry.date()))
+                .or_insert_with(|| {
+                    groups.push(vec![]);
+                    groups.len() - 1
+                });
+            groups[idx].push(entry);
+        }
+
+        groups.retain(|group| group.len() > 1);
+        groups
+    }
+}
+
+/// Parses every line of the `LIST` response.
+/// The same as [`FtpListing::parse`].
+pub fn parse_listing(listing: &str) -> FtpListing {
+    FtpListing::parse(listing)
+}
@@ -0,0 +1,224 @@
+use std::{
+    convert::TryFrom,
+    fmt::{self, Display},
+};
+
+use crate::error::{FtpEntryError, FtpEntryField};
+
+/// Read, write and execute permissions of one class of users.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct FtpEntryPermissionBits {
+    pub read: bool,
+    pub write: bool,
+    pub execute: bool,
+}
+
+impl FtpEntryPermissionBits {
+    fn from_mode(mode: u32) -> Self {
+        Self {
+            read: mode & 0o4 != 0,
+            write: mode & 0o2 != 0,
+            execute: mode & 0o1 != 0,
+        }
+    }
+
+    fn mode(&self) -> u32 {
+        (self.read as u32) << 2 | (self.write as u32) << 1 | self.execute as u32
+    }
+}
+
+/// Permissions of the Unix-like entry, e.g. `rwxr-sr-t`.
+/// ```rust
+/// # use std::convert::TryFrom;
+/// # use ftp_cmd_list_parse::FtpEntryPermissions;
+/// let permissions = FtpEntryPermissions::try_from("rwxr-sr-t").unwrap();
+///
+/// assert_eq!(permissions.mode(), 0o3755);
+/// assert_eq!(permissions.group().execute, true);
+/// assert_eq!(permissions.setgid(), true);
+/// assert_eq!(permissions.to_string(), "rwxr-sr-t");
+/// assert_eq!(FtpEntryPermissions::from_mode(0o3755), permissions);
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct FtpEntryPermissions {
+    user: FtpEntryPermissionBits,
+    group: FtpEntryPermissionBits,
+    other: FtpEntryPermissionBits,
+    setuid: bool,
+    setgid: bool,
+    sticky: bool,
+    mandatory_locking: bool,
+    /// The string sent by the server with the sticky bit folded
+    /// into the execute permission of others.
+    normalized: [u8; 9],
+}
+
+impl FtpEntryPermissions {
+    /// Returns permissions by given mode. Only the lower 12 bits are used,
+    /// the type of the file is ignored.
+    pub fn from_mode(mode: u32) -> Self {
+        let user = FtpEntryPermissionBits::from_mode(mode >> 6);
+        let group = FtpEntryPermissionBits::from_mode(mode >> 3);
+        let other = FtpEntryPermissionBits::from_mode(mode);
+        let setuid = mode & 0o4000 != 0;
+        let setgid = mode & 0o2000 != 0;
+
+        let mut normalized = [b'-'; 9];
+        for (idx, (class, special)) in [(user, setuid), (group, setgid), (other, false)]
+            .iter()
+            .enumerate()
+        {
+            let offset = idx * 3;
+            if class.read {
+                normalized[offset] = b'r';
+            }
+            if class.write {
+                normalized[offset + 1] = b'w';
+            }
+            normalized[offset + 2] = match (special, class.execute) {
+                (true, true) => b's',
+                (true, false) => b'S',
+                (false, true) => b'x',
+                (false, false) => b'-',
+            };
+        }
+
+        Self {
+            user,
+            group,
+            other,
+            setuid,
+            setgid,
+            sticky: mode & 0o1000 != 0,
+            mandatory_locking: false,
+            normalized,
+        }
+    }
+
+    /// Returns the mode, e.g. `0o1777` for `rwxrwxrwt`.
+    pub fn mode(&self) -> u32 {
+        (self.setuid as u32) << 11
+            | (self.setgid as u32) << 10
+            | (self.sticky as u32) << 9
+            | self.user.mode() << 6
+            | self.group.mode() << 3
+            | self.other.mode()
+    }
+
+    /// Returns permissions of the owner.
+    pub fn user(&self) -> FtpEntryPermissionBits {
+        self.user
+    }
+
+    /// Returns permissions of the group.
+    pub fn group(&self) -> FtpEntryPermissionBits {
+        self.group
+    }
+
+    /// Returns permissions of everyone else.
+    pub fn other(&self) -> FtpEntryPermissionBits {
+        self.other
+    }
+
+    /// Returns true if the set-user-ID bit is set.
+    pub fn setuid(&self) -> bool {
+        self.setuid
+    }
+
+    /// Returns true if the set-group-ID bit is set.
+    pub fn setgid(&self) -> bool {
+        self.setgid
+    }
+
+    /// Returns true if the sticky bit is set.
+    pub fn sticky(&self) -> bool {
+        self.sticky
+    }
+
+    /// Returns true if the server marked the entry with `L`, which means
+    /// mandatory locking: the set-group-ID bit without the group execute bit.
+    /// Permissions built by [`from_mode`](#method.from_mode) show it as `S`.
+    pub fn mandatory_locking(&self) -> bool {
+        self.mandatory_locking
+    }
+
+    /// Returns the permissions with the sticky bit folded into the execute
+    /// permission of others, e.g. `rwxrwxrwx` for `rwxrwxrwt`.
+    /// Use [`Display`] to get them the way the server sent them.
+    pub fn as_str(&self) -> &str {
+        // Only ASCII characters are ever stored.
+        std::str::from_utf8(&self.normalized).unwrap()
+    }
+}
+
+impl TryFrom<&str> for FtpEntryPermissions {
+    type Error = FtpEntryError;
+
+    /// Parses the nine permission characters, e.g. `rwxr-sr-t` or `rwx--L---`.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let error = |offset| FtpEntryError::new(None, FtpEntryField::Permissions, offset);
+
+        let chars = value.as_bytes();
+        if chars.len() != 9 {
+            return Err(error(chars.len().min(9)));
+        }
+
+        let mut classes = [FtpEntryPermissionBits::default(); 3];
+        let mut special = [false; 3];
+        for (idx, class) in classes.iter_mut().enumerate() {
+            let offset = idx * 3;
+            class.read = match chars[offset] {
+                b'r' => true,
+                b'-' => false,
+                _ => return Err(error(offset)),
+            };
+            class.write = match chars[offset + 1] {
+                b'w' => true,
+                b'-' => false,
+                _ => return Err(error(offset + 1)),
+            };
+            let set = if idx == 2 { b't' } else { b's' };
+            (class.execute, special[idx]) = match chars[offset + 2] {
+                b'x' => (true, false),
+                b'-' => (false, false),
+                b'L' if idx == 1 => (false, true),
+                c if c == set => (true, true),
+                c if c == set.to_ascii_uppercase() => (false, true),
+                _ => return Err(error(offset + 2)),
+            };
+        }
+
+        let mut normalized = [0; 9];
+        normalized.copy_from_slice(chars);
+        if special[2] {
+            normalized[8] = if classes[2].execute { b'x' } else { b'-' };
+        }
+
+        Ok(Self {
+            user: classes[0],
+            group: classes[1],
+            other: classes[2],
+            setuid: special[0],
+            setgid: special[1],
+            sticky: special[2],
+            mandatory_locking: chars[5] == b'L',
+            normalized,
+        })
+    }
+}
+
+impl Display for FtpEntryPermissions {
+    /// Writes the permissions the way the server sent them, e.g. `rwxr-sr-t`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", &self.as_str()[..8])?;
+
+        let execute = match (self.sticky, self.other.execute) {
+            (true, true) => 't',
+            (true, false) => 'T',
+            (false, true) => 'x',
+            (false, false) => '-',
+        };
+        write!(f, "{}", execute)
+    }
+}
@@ -0,0 +1,91 @@
+use std::{convert::TryFrom, str};
+
+use crate::{FtpEntry, FtpEntryError};
+
+#[cfg(feature = "encoding")]
+pub use encoding_rs::Encoding;
+
+/// Entry parsed from raw bytes of the line, see [`FtpEntry::try_from_bytes`].
+#[derive(Debug)]
+pub struct FtpEntryRaw {
+    /// The entry parsed from the decoded line.
+    pub entry: FtpEntry,
+    /// The name of the entry exactly as the server sent it,
+    /// to be used in `RETR`, `CWD` and other commands.
+    pub raw_name: Vec<u8>,
+}
+
+impl FtpEntry {
+    /// Parses raw bytes of the line sent by the server, keeping the name bytes.
+    ///
+    /// The line is read as UTF-8 if it is valid, otherwise as Latin-1, so
+    /// every byte becomes one character and the name of the entry can be
+    /// encoded back without any loss. Offsets of errors are in bytes of the
+    /// given line. Use [`try_from_bytes_with_encoding`](#method.try_from_bytes_with_encoding)
+    /// to get readable names of servers with other encodings.
+    /// ```rust
+    /// # use ftp_cmd_list_parse::FtpEntry;
+    /// let line = b"-rw-r--r--   1 owner   group    1024 Sep 02  2012 caf\xe9.txt";
+    /// let raw = FtpEntry::try_from_bytes(line).unwrap();
+    ///
+    /// assert_eq!(raw.entry.name(), "caf\u{e9}.txt");
+    /// assert_eq!(raw.raw_name, b"caf\xe9.txt");
+    /// ```
+    pub fn try_from_bytes(value: &[u8]) -> Result<FtpEntryRaw, FtpEntryError> {
+        if let Ok(line) = str::from_utf8(value) {
+            let entry = FtpEntry::try_from(line)?;
+            let raw_name = entry.name().as_bytes().to_vec();
+            return Ok(FtpEntryRaw { entry, raw_name });
+        }
+
+        let line: String = value.iter().map(|&b| char::from(b)).collect();
+        let entry = FtpEntry::try_from(line.as_str())
+            .map_err(|err| err.map_offset(|offset| line[..offset].chars().count()))?;
+        // Every character of the line is below U+0100.
+        let raw_name = entry.name().chars().map(|c| c as u8).collect();
+
+        Ok(FtpEntryRaw { entry, raw_name })
+    }
+
+    /// Parses raw bytes of the line sent by a server with the given encoding,
+    /// e.g. `WINDOWS_1251` or `SHIFT_JIS`, keeping the name bytes.
+    ///
+    /// The name of the entry is encoded back to get the raw name. If the line
+    /// is malformed in the encoding, or the name cannot be encoded back to
+    /// the same bytes, the line is parsed by [`try_from_bytes`](#method.try_from_bytes).
+    /// ```rust
+    /// # use ftp_cmd_list_parse::{Encoding, FtpEntry};
+    /// let encoding = Encoding::for_label(b"windows-1251").unwrap();
+    /// let line = b"-rw-r--r--   1 owner   group    1024 Sep 02  2012 \xcf\xf0\xe8\xe2\xe5\xf2.txt";
+    /// let raw = FtpEntry::try_from_bytes_with_encoding(line, encoding).unwrap();
+    ///
+    /// assert_eq!(raw.entry.name(), "Привет.txt");
+    /// assert_eq!(raw.raw_name, b"\xcf\xf0\xe8\xe2\xe5\xf2.txt");
+    /// ```
+    #[cfg(feature = "encoding")]
+    pub fn try_from_bytes_with_encoding(
+        value: &[u8],
+        encoding: &'static Encoding,
+    ) -> Result<FtpEntryRaw, FtpEntryError> {
+        let line = match encoding.decode_without_bom_handling_and_without_replacement(value) {
+            Some(line) => line,
+            None => return FtpEntry::try_from_bytes(value),
+        };
+
+        let entry = FtpEntry::try_from(line.as_ref())
+            .map_err(|err| err.map_offset(|offset| encoding.encode(&line[..offset]).0.len()))?;
+        let (raw_name, _, unmappable) = encoding.encode(entry.name());
+        let found = raw_name.is_empty()
+            || value
+                .windows(raw_name.len())
+                .any(|window| window == raw_name.as_ref());
+        if unmappable || !found {
+            return FtpEntry::try_from_bytes(value);
+        }
+
+        Ok(FtpEntryRaw {
+            raw_name: raw_name.into_owned(),
+            entry,
+        })
+    }
+}
@@ -0,0 +1,124 @@
+#![allow(clippy::bool_assert_comparison)]
+
+mod as400 {
+    use std::convert::TryFrom;
+
+    use ::ftp_cmd_list_parse::*;
+
+    #[test]
+    fn directory() {
+        let row = "QSYS           77824 02/23/00 15:09:55 *DIR       RCHFOLDER/";
+
+        let ftpentry = FtpEntry::try_from(row);
+        assert!(ftpentry.is_ok());
+        let ftpentry = ftpentry.unwrap();
+
+        assert_eq!(ftpentry.kind(), FtpEntryKind::Directory);
+        assert_eq!(ftpentry.name(), "RCHFOLDER");
+        assert_eq!(ftpentry.size(), 77824);
+        assert_eq!(ftpentry.date_str(), "02/23/00 15:09:55");
+        assert_eq!(
+            ftpentry.date(),
+            FtpEntryDate::from_ymd_hms(2000, 2, 23, 15, 9, 55)
+        );
+        assert_eq!(ftpentry.is_as400_type(), true);
+        assert_eq!(ftpentry.is_unix_type(), false);
+
+        let ftpentry_as400 = ftpentry.to_as400_type();
+
+        assert_eq!(ftpentry_as400.owner.as_deref(), Some("QSYS"));
+        assert_eq!(ftpentry_as400.object_type, "*DIR");
+    }
+
+    #[test]
+    fn stream_file() {
+        let row = "PEP             4019 04/03/18 18:58:16 *STMF      einladung zwei.zip";
+        let ftpentry = FtpEntryAs400::try_from(row).unwrap();
+
+        assert_eq!(ftpentry.kind(), FtpEntryKind::File);
+        assert_eq!(ftpentry.name(), "einladung zwei.zip");
+        assert_eq!(ftpentry.size(), 4019);
+        assert_eq!(
+            ftpentry.date(),
+            FtpEntryDate::from_ymd_hms(2018, 4, 3, 18, 58, 16)
+        );
+        assert_eq!(ftpentry.object_type, "*STMF");
+    }
+
+    #[test]
+    fn library_file_and_member() {
+        let row = "QSYS           24576 11/05/19 08:12:40 *LIB       QGPL";
+        let ftpentry = FtpEntryAs400::try_from(row).unwrap();
+
+        assert_eq!(ftpentry.kind(), FtpEntryKind::Directory);
+        assert_eq!(ftpentry.object_type, "*LIB");
+
+        let row = "QPGMR          12288 06/08/20 17:49:31 *FILE      QGPL/QCLSRC.FILE";
+        let ftpentry = FtpEntryAs400::try_from(row).unwrap();
+
+        assert_eq!(ftpentry.kind(), FtpEntryKind::File);
+        assert_eq!(ftpentry.name(), "QGPL/QCLSRC.FILE");
+
+        let row = "                                       *MEM       QGPL/QCLSRC.FILE/START.MBR";
+        let ftpentry = FtpEntryAs400::try_from(row).unwrap();
+
+        assert_eq!(ftpentry.kind(), FtpEntryKind::File);
+        assert_eq!(ftpentry.name(), "QGPL/QCLSRC.FILE/START.MBR");
+        assert_eq!(ftpentry.size(), 0);
+        assert_eq!(ftpentry.date(), None);
+        assert_eq!(ftpentry.date_str(), "");
+        assert_eq!(ftpentry.owner, None);
+        assert_eq!(ftpentry.object_type, "*MEM");
+    }
+
+    #[test]
+    fn unknown_object_type() {
+        let row = "QSYS            8192 01/15/21 09:30:00 *PGM       QGPL/HELLO.PGM";
+        let ftpentry = FtpEntryAs400::try_from(row).unwrap();
+
+        assert_eq!(ftpentry.kind(), FtpEntryKind::UNKNOWN);
+        assert_eq!(ftpentry.object_type, "*PGM");
+    }
+
+    #[test]
+    fn invalid_date() {
+        let row = "QSYS           77824 02/30/00 15:09:55 *DIR       RCHFOLDER/";
+        let err = FtpEntryAs400::try_from(row).unwrap_err();
+
+        assert_eq!(err.format(), Some(FtpEntryFormat::As400));
+        assert_eq!(err.field(), FtpEntryField::Timestamp);
+        assert_eq!(err.offset(), 21);
+    }
+
+    #[test]
+    fn partial_lines_are_not_entries() {
+        for row in &[
+            "hello *WORLD foo",
+            "QSYS           77824 *DIR       RCHFOLDER/",
+            "   77824 02/23/00 15:09:55 *DIR       RCHFOLDER/",
+            "   *DIR       RCHFOLDER/",
+        ] {
+            assert!(FtpEntryAs400::try_from(*row).is_err(), "{}", row);
+        }
+
+        let err = FtpEntry::try_from("hello *WORLD foo").unwrap_err();
+        assert_ne!(err.format(), Some(FtpEntryFormat::As400));
+
+        let listing = FtpListing::parse("hello *WORLD foo\n");
+        assert_eq!(listing.entries.len(), 0);
+        assert_eq!(listing.unparsed.len(), 1);
+    }
+
+    #[test]
+    fn word_and_number_are_not_reported_as_as400() {
+        for row in &["total 12", "Error 550 no such file", "ABC 12 something"] {
+            let err = FtpEntry::try_from(*row).unwrap_err();
+            assert_ne!(err.format(), Some(FtpEntryFormat::As400), "{}", row);
+        }
+
+        let err = FtpEntryAs400::try_from("ABC 12 something").unwrap_err();
+        assert_eq!(err.format(), Some(FtpEntryFormat::As400));
+        assert_eq!(err.field(), FtpEntryField::Timestamp);
+        assert_eq!(err.offset(), 7);
+    }
+}
@@ -0,0 +1,73 @@
+mod date {
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    use ::ftp_cmd_list_parse::*;
+
+    fn at(secs: u64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(secs)
+    }
+
+    #[test]
+    fn infer_year_keeps_known_year() {
+        let date = FtpEntryDate::from_ymd(2012, 12, 21).unwrap();
+
+        assert_eq!(date.infer_year(at(1_609_459_200)), date);
+    }
+
+    #[test]
+    fn infer_year_recent_file() {
+        // 2020-06-01T00:00:00Z
+        let date = FtpEntryDate::from_md_hm(5, 4, 11, 1)
+            .unwrap()
+            .infer_year(at(1_590_969_600));
+
+        assert_eq!(date.year(), Some(2020));
+        assert_eq!(date.month(), 5);
+        assert_eq!(date.day(), 4);
+        assert_eq!(date.hour(), Some(11));
+        assert_eq!(date.minute(), Some(1));
+        assert_eq!(date.precision(), FtpEntryDatePrecision::InferredYear);
+    }
+
+    #[test]
+    fn infer_year_across_new_year() {
+        // 2021-01-01T00:30:00Z
+        let now = at(1_609_461_000);
+
+        let date = FtpEntryDate::from_md_hm(12, 31, 23, 50).unwrap();
+        assert_eq!(date.infer_year(now).year(), Some(2020));
+
+        let date = FtpEntryDate::from_md_hm(1, 1, 0, 10).unwrap();
+        assert_eq!(date.infer_year(now).year(), Some(2021));
+    }
+
+    #[test]
+    fn infer_year_server_ahead_of_utc() {
+        // 2020-12-31T22:30:00Z, server is at UTC+3 and already in 2021.
+        let now = at(1_609_453_800);
+        let date = FtpEntryDate::from_md_hm(1, 1, 1, 15).unwrap();
+
+        assert_eq!(date.infer_year(now).year(), Some(2021));
+        assert_eq!(date.infer_year_at_offset(now, 3 * 3600).year(), Some(2021));
+    }
+
+    #[test]
+    fn infer_year_with_offset_rejects_future() {
+        // 2020-12-31T22:30:00Z, server is at UTC.
+        let now = at(1_609_453_800);
+        let date = FtpEntryDate::from_md_hm(12, 31, 23, 45).unwrap();
+
+        assert_eq!(date.infer_year(now).year(), Some(2020));
+        assert_eq!(date.infer_year_at_offset(now, 0).year(), Some(2019));
+    }
+
+    #[test]
+    fn infer_year_february_29() {
+        // 2021-03-01T00:00:00Z
+        let date = FtpEntryDate::from_md_hm(2, 29, 12, 0)
+            .unwrap()
+            .infer_year(at(1_614_556_800));
+
+        assert_eq!(date.year(), Some(2020));
+    }
+}
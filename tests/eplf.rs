@@ -0,0 +1,80 @@
+#![allow(clippy::bool_assert_comparison)]
+
+mod eplf {
+    use std::convert::TryFrom;
+
+    use ::ftp_cmd_list_parse::*;
+
+    #[test]
+    fn normal_file() {
+        let row = "+i8388621.48594,m825718503,r,s280,\tdjb.html";
+
+        let ftpentry = FtpEntry::try_from(row);
+        assert!(ftpentry.is_ok());
+        let ftpentry = ftpentry.unwrap();
+
+        assert_eq!(ftpentry.kind(), FtpEntryKind::File);
+        assert_eq!(ftpentry.name(), "djb.html");
+        assert_eq!(ftpentry.size(), 280);
+        assert_eq!(ftpentry.date_str(), "825718503");
+        assert_eq!(
+            ftpentry.date(),
+            FtpEntryDate::from_ymd_hms(1996, 3, 1, 22, 15, 3)
+        );
+        assert_eq!(ftpentry.is_eplf_type(), true);
+        assert_eq!(ftpentry.is_unix_type(), false);
+
+        let ftpentry_eplf = ftpentry.to_eplf_type();
+
+        assert_eq!(ftpentry_eplf.cwd, false);
+        assert_eq!(ftpentry_eplf.retr, true);
+        assert_eq!(ftpentry_eplf.mtime, Some(825718503));
+        assert_eq!(ftpentry_eplf.unique, Some("8388621.48594".to_string()));
+        assert_eq!(ftpentry_eplf.unix_mode, None);
+    }
+
+    #[test]
+    fn normal_directory() {
+        let row = "+i8388621.50690,m824255907,/,\t514";
+
+        let ftpentry = FtpEntryEplf::try_from(row).unwrap();
+
+        assert_eq!(ftpentry.kind(), FtpEntryKind::Directory);
+        assert_eq!(ftpentry.name(), "514");
+        assert_eq!(ftpentry.size(), 0);
+        assert_eq!(ftpentry.cwd, true);
+        assert_eq!(ftpentry.retr, false);
+    }
+
+    #[test]
+    fn unix_permissions_and_unknown_facts() {
+        let row = "+up644,xfoo,r,\tname with spaces";
+
+        let ftpentry = FtpEntryEplf::try_from(row).unwrap();
+
+        assert_eq!(ftpentry.kind(), FtpEntryKind::File);
+        assert_eq!(ftpentry.name(), "name with spaces");
+        assert_eq!(ftpentry.unix_mode, Some(0o644));
+        assert_eq!(ftpentry.date(), None);
+        assert_eq!(ftpentry.date_str(), "");
+    }
+
+    #[test]
+    fn error_invalid_mtime() {
+        let row = "+i8388621.48594,m82571x8503,r,s280,\tdjb.html";
+
+        let err = FtpEntry::try_from(row).unwrap_err();
+        assert_eq!(err.format(), Some(FtpEntryFormat::Eplf));
+        assert_eq!(err.field(), FtpEntryField::Timestamp);
+        assert_eq!(err.offset(), 16);
+    }
+
+    #[test]
+    fn error_missing_tab() {
+        let row = "+i8388621.48594,m825718503,r,s280, djb.html";
+
+        let err = FtpEntryEplf::try_from(row).unwrap_err();
+        assert_eq!(err.field(), FtpEntryField::Name);
+        assert_eq!(err.offset(), row.len());
+    }
+}
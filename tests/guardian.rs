@@ -0,0 +1,105 @@
+#![allow(clippy::bool_assert_comparison)]
+
+mod guardian {
+    use std::convert::TryFrom;
+
+    use ::ftp_cmd_list_parse::*;
+
+    #[test]
+    fn file_with_code() {
+        let row = r#"EDITFILE       101         8192  12-Jul-99 11:47:36  255, 0 "oooo""#;
+
+        let ftpentry = FtpEntry::try_from(row);
+        assert!(ftpentry.is_ok());
+        let ftpentry = ftpentry.unwrap();
+
+        assert_eq!(ftpentry.kind(), FtpEntryKind::File);
+        assert_eq!(ftpentry.name(), "EDITFILE");
+        assert_eq!(ftpentry.size(), 8192);
+        assert_eq!(ftpentry.date_str(), "12-Jul-99 11:47:36");
+        assert_eq!(
+            ftpentry.date(),
+            FtpEntryDate::from_ymd_hms(1999, 7, 12, 11, 47, 36)
+        );
+        assert_eq!(ftpentry.is_guardian_type(), true);
+        assert_eq!(ftpentry.is_unix_type(), false);
+
+        let ftpentry_guardian = ftpentry.to_guardian_type();
+
+        assert_eq!(ftpentry_guardian.file_code, Some(101));
+        assert_eq!(ftpentry_guardian.group_id, 255);
+        assert_eq!(ftpentry_guardian.user_id, 0);
+        assert_eq!(
+            ftpentry_guardian.security,
+            FtpEntryGuardianSecurity {
+                read: FtpEntryGuardianAccess::Owner,
+                write: FtpEntryGuardianAccess::Owner,
+                execute: FtpEntryGuardianAccess::Owner,
+                purge: FtpEntryGuardianAccess::Owner,
+            }
+        );
+    }
+
+    #[test]
+    fn file_without_code() {
+        let row = r#"BLOCKED                       0  30-May-02 15:18:09  10,133 "nuc-""#;
+        let ftpentry = FtpEntryGuardian::try_from(row).unwrap();
+
+        assert_eq!(ftpentry.name(), "BLOCKED");
+        assert_eq!(ftpentry.size(), 0);
+        assert_eq!(
+            ftpentry.date(),
+            FtpEntryDate::from_ymd_hms(2002, 5, 30, 15, 18, 9)
+        );
+        assert_eq!(ftpentry.file_code, None);
+        assert_eq!(ftpentry.group_id, 10);
+        assert_eq!(ftpentry.user_id, 133);
+        assert_eq!(
+            ftpentry.security,
+            FtpEntryGuardianSecurity {
+                read: FtpEntryGuardianAccess::Network,
+                write: FtpEntryGuardianAccess::NetworkOwner,
+                execute: FtpEntryGuardianAccess::Community,
+                purge: FtpEntryGuardianAccess::SuperId,
+            }
+        );
+    }
+
+    #[test]
+    fn invalid_security() {
+        let row = r#"EDITFILE       101         8192  12-Jul-99 11:47:36  255,255 "oxoo""#;
+        let err = FtpEntryGuardian::try_from(row).unwrap_err();
+
+        assert_eq!(err.format(), Some(FtpEntryFormat::Guardian));
+        assert_eq!(err.field(), FtpEntryField::Permissions);
+        assert_eq!(err.offset(), 61);
+    }
+
+    #[test]
+    fn garbage_is_not_reported_as_guardian() {
+        for row in &["hello world", "this is not an entry", "total 12"] {
+            let err = FtpEntry::try_from(*row).unwrap_err();
+            assert_ne!(err.format(), Some(FtpEntryFormat::Guardian), "{}", row);
+        }
+
+        let err = FtpEntryGuardian::try_from("hello world").unwrap_err();
+        assert_eq!(err.format(), Some(FtpEntryFormat::Guardian));
+        assert_eq!(err.field(), FtpEntryField::Size);
+        assert_eq!(err.offset(), 6);
+    }
+
+    #[test]
+    fn listing_with_header() {
+        let listing = "File         Code             EOF  Last Modification    Owner  RWEP\r\n\
+            EDITFILE       101         8192  12-Jul-99 11:47:36  255, 0 \"oooo\"\r\n\
+            BLOCKED                       0  30-May-02 15:18:09  10,133 \"nuc-\"\r\n";
+
+        let listing = FtpListing::parse(listing);
+
+        assert_eq!(listing.entries.len(), 2);
+        assert_eq!(listing.entries[0].name(), "EDITFILE");
+        assert_eq!(listing.entries[1].name(), "BLOCKED");
+        assert_eq!(listing.entries[1].is_guardian_type(), true);
+        assert_eq!(listing.unparsed, vec![]);
+    }
+}
@@ -0,0 +1,190 @@
+#![allow(clippy::bool_assert_comparison)]
+
+mod listing {
+    use ::ftp_cmd_list_parse::*;
+
+    #[test]
+    fn unix_listing_with_total() {
+        let listing = "total 16\n\
+            drwxr-xr-x  10 root   root    4096 Dec 21  2012 usr\n\
+            -rw-rw-rw-   1 owner   group    7045120 Sep 02  2012 music.mp3\n";
+
+        let listing = parse_listing(listing);
+
+        assert_eq!(listing.entries.len(), 2);
+        assert_eq!(listing.entries[0].name(), "usr");
+        assert_eq!(listing.entries[1].name(), "music.mp3");
+        assert_eq!(listing.unparsed, vec![]);
+    }
+
+    #[test]
+    fn msdos_listing_with_crlf() {
+        let listing = "08-22-18  02:05PM       <DIR>          wwwroot\r\n\
+            08-22-18  12:59PM                99710 iisstart.png\r\n";
+
+        let listing = FtpListing::parse(listing);
+
+        assert_eq!(listing.entries.len(), 2);
+        assert_eq!(listing.entries[0].name(), "wwwroot");
+        assert_eq!(listing.entries[0].kind(), FtpEntryKind::Directory);
+        assert_eq!(listing.entries[1].name(), "iisstart.png");
+        assert_eq!(listing.entries[1].is_msdos_type(), true);
+        assert_eq!(listing.unparsed, vec![]);
+    }
+
+    #[test]
+    fn listing_with_blank_and_unparsed_lines() {
+        let listing = "total 8\r\n\
+            \r\n\
+            drwxr-xr-x  10 root   root    4096 Dec 21  2012 usr\r\n\
+            this is not an entry\n\
+            \n\
+            lrwxrwxrwx 1 root root 51 Apr  4 23:57 www -> /var/www\n\
+            -rw-rw-rw-   1 owner";
+
+        let listing = FtpListing::parse(listing);
+
+        assert_eq!(listing.entries.len(), 2);
+        assert_eq!(listing.entries[0].name(), "usr");
+        assert_eq!(listing.entries[1].name(), "www");
+        assert_eq!(listing.unparsed.len(), 2);
+        assert_eq!(listing.unparsed[0].line_number, 4);
+        assert_eq!(listing.unparsed[0].line, "this is not an entry");
+        assert_eq!(listing.unparsed[1].line_number, 7);
+        assert_eq!(listing.unparsed[1].line, "-rw-rw-rw-   1 owner");
+        assert_eq!(
+            listing.unparsed[1].error.format(),
+            Some(FtpEntryFormat::Unix)
+        );
+        assert_eq!(listing.unparsed[1].error.field(), FtpEntryField::Group);
+        assert_eq!(listing.unparsed[1].error.offset(), 20);
+    }
+
+    #[test]
+    fn hard_link_candidates() {
+        let listing = "-rw-r--r--   3 root   root    1024 Dec 21  2012 a.txt\n\
+            -rw-r--r--   1 root   root    1024 Dec 21  2012 b.txt\n\
+            -rw-r--r--   2 root   root    2048 Sep 02  2012 c.txt\n\
+            -rw-r--r--   3 root   root    1024 Dec 21  2012 d.txt\n\
+            drwxr-xr-x   2 root   root    2048 Sep 02  2012 dir\n\
+            -rw-r--r--   2 root   root    2048 Sep 02  2012 e.txt\n\
+            -rw-r--r--   2 root   root    4096 Sep 02  2012 f.txt\n";
+
+        let listing = FtpListing::parse(listing);
+        let groups = listing.hard_link_candidates();
+        let names = groups
+            .iter()
+            .map(|group| group.iter().map(|entry| entry.name()).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        assert_eq!(names, vec![vec!["a.txt", "d.txt"], vec!["c.txt", "e.txt"]]);
+        assert_eq!(groups[0][0].link_count, 3);
+
+        // Device numbers of files are not taken for a zero size.
+        let listing = "-rw-r--r--   2 root   root   1,234 Dec 21  2012 a.txt\n\
+            -rw-r--r--   2 root   root   1,999 Dec 21  2012 b.txt\n";
+
+        let listing = FtpListing::parse(listing);
+        assert_eq!(listing.unparsed.len(), 2);
+        assert!(listing.hard_link_candidates().is_empty());
+    }
+
+    #[test]
+    fn aligned_owners_with_spaces() {
+        let listing = "-rw-r--r--   1 john doe     domain users   1024 Sep 02  2012 a.txt\n\
+            -rw-r--r--   1 root         wheel          2048 Sep 02  2012 b.txt\n\
+            drwxr-xr-x   2 mary ann lee staff          4096 Dec 21 10:13 dir\n";
+
+        let listing = FtpListing::parse_aligned(listing);
+        assert_eq!(listing.unparsed, vec![]);
+
+        let owners = listing
+            .entries
+            .into_iter()
+            .map(|entry| {
+                let entry = entry.to_unix_type();
+                let name = entry.name().to_string();
+                (entry.owner, entry.group, name)
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            owners,
+            vec![
+                (
+                    Some("john doe".into()),
+                    Some("domain users".into()),
+                    "a.txt".into()
+                ),
+                (Some("root".into()), Some("wheel".into()), "b.txt".into()),
+                (
+                    Some("mary ann lee".into()),
+                    Some("staff".into()),
+                    "dir".into()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn aligned_names_with_whitespace() {
+        let listing = "total 12\n\
+            -rw-r--r--   1 root root 1024 Sep 02  2012  leading.txt\n\
+            -rw-r--r--   1 root root 1024 Sep 02  2012 trailing.txt  \n\
+            lrwxrwxrwx   1 root root    7 Sep 02 10:13   link -> target\n";
+
+        let listing = FtpListing::parse_aligned(listing);
+
+        assert_eq!(listing.entries.len(), 3);
+        assert_eq!(listing.entries[0].name(), " leading.txt");
+        assert_eq!(listing.entries[1].name(), "trailing.txt  ");
+        assert_eq!(listing.entries[2].name(), "  link");
+
+        let listing = FtpListing::parse(
+            "-rw-r--r--   1 root root 1024 Sep 02  2012  leading.txt\n\
+            -rw-r--r--   1 root root 1024 Sep 02  2012 plain.txt\n",
+        );
+        assert_eq!(listing.entries[0].name(), "leading.txt");
+    }
+
+    #[test]
+    fn aligned_with_ambiguous_group_column() {
+        let listing = "-rw-r--r--   1 John Doe  staff  1024 Sep 02  2012 a.txt\n\
+            08-22-18  12:59PM                99710 logo.jpg\n";
+
+        let listing = FtpListing::parse_aligned(listing);
+        assert_eq!(listing.entries.len(), 2);
+        assert_eq!(listing.entries[1].is_msdos_type(), true);
+
+        // A single line cannot tell whether `Doe` is the group,
+        // so the line is parsed as usual.
+        let unix = listing.entries.into_iter().next().unwrap().to_unix_type();
+        assert_eq!(unix.owner.as_deref(), Some("John Doe"));
+        assert_eq!(unix.group.as_deref(), Some("staff"));
+        assert_eq!(unix.name(), "a.txt");
+    }
+
+    #[test]
+    fn aligned_single_ownership_column() {
+        let listing = "-rw-r--r--    1 ftp          1024 Sep 02  2012 a.txt\n\
+            -rw-r--r--    1 www-data    20480 Sep 02  2012  b.txt\n";
+
+        let listing = FtpListing::parse_aligned(listing);
+        assert_eq!(listing.entries.len(), 2);
+
+        let unix = listing.entries.into_iter().nth(1).unwrap().to_unix_type();
+        assert_eq!(unix.ownership.as_deref(), Some("www-data"));
+        assert_eq!(unix.owner, None);
+        assert_eq!(unix.group, None);
+        assert_eq!(unix.size(), 20480);
+        assert_eq!(unix.name(), " b.txt");
+    }
+
+    #[test]
+    fn empty_listing() {
+        let listing = FtpListing::parse("");
+
+        assert_eq!(listing.entries.len(), 0);
+        assert_eq!(listing.unparsed, vec![]);
+    }
+}
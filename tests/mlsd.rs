@@ -0,0 +1,128 @@
+#![allow(clippy::bool_assert_comparison)]
+
+mod mlsd {
+    use std::convert::TryFrom;
+
+    use ::ftp_cmd_list_parse::*;
+
+    #[test]
+    fn normal_file() {
+        let row =
+            "type=file;size=1830;modify=20120921101300;perm=adfrw;unique=802U5A2DA; music.mp3";
+
+        let ftpentry = FtpEntry::try_from(row);
+        assert!(ftpentry.is_ok());
+        let ftpentry = ftpentry.unwrap();
+
+        assert_eq!(ftpentry.kind(), FtpEntryKind::File);
+        assert_eq!(ftpentry.name(), "music.mp3");
+        assert_eq!(ftpentry.size(), 1830);
+        assert_eq!(ftpentry.date_str(), "20120921101300");
+        assert_eq!(
+            ftpentry.date(),
+            FtpEntryDate::from_ymd_hms(2012, 9, 21, 10, 13, 0)
+        );
+        assert_eq!(ftpentry.is_mlsd_type(), true);
+        assert_eq!(ftpentry.is_unix_type(), false);
+
+        let ftpentry_mlsd = ftpentry.to_mlsd_type();
+
+        assert_eq!(ftpentry_mlsd.entry_type, Some(FtpEntryMlsdType::File));
+        assert_eq!(ftpentry_mlsd.unique, Some("802U5A2DA".to_string()));
+        assert_eq!(
+            ftpentry_mlsd.perm,
+            Some(FtpEntryMlsdPermissions {
+                append: true,
+                delete: true,
+                rename: true,
+                retrieve: true,
+                store: true,
+                ..Default::default()
+            })
+        );
+        assert_eq!(ftpentry_mlsd.create, None);
+        assert_eq!(ftpentry_mlsd.other_facts, vec![]);
+    }
+
+    #[test]
+    fn current_and_parent_directories() {
+        let cdir = FtpEntryMlsd::try_from("type=cdir;modify=20120921101300; /pub").unwrap();
+        assert_eq!(cdir.kind(), FtpEntryKind::Directory);
+        assert_eq!(cdir.name(), "/pub");
+        assert_eq!(cdir.entry_type, Some(FtpEntryMlsdType::Cdir));
+
+        let pdir = FtpEntryMlsd::try_from("Type=PDir;modify=20120921101300; ..").unwrap();
+        assert_eq!(pdir.kind(), FtpEntryKind::Directory);
+        assert_eq!(pdir.entry_type, Some(FtpEntryMlsdType::Pdir));
+    }
+
+    #[test]
+    fn unix_facts_and_name_with_spaces() {
+        let row = "type=dir;modify=20200101000000.123;create=20191231235959;UNIX.mode=0755;UNIX.owner=root;UNIX.group=wheel;lang=en;media-type=text/plain;x.custom=1; my dir; with spaces";
+
+        let ftpentry = FtpEntryMlsd::try_from(row).unwrap();
+
+        assert_eq!(ftpentry.kind(), FtpEntryKind::Directory);
+        assert_eq!(ftpentry.name(), "my dir; with spaces");
+        assert_eq!(ftpentry.size(), 0);
+        assert_eq!(
+            ftpentry.date(),
+            FtpEntryDate::from_ymd_hms(2020, 1, 1, 0, 0, 0)
+        );
+        assert_eq!(
+            ftpentry.create,
+            FtpEntryDate::from_ymd_hms(2019, 12, 31, 23, 59, 59)
+        );
+        assert_eq!(ftpentry.unix_mode, Some(0o755));
+        assert_eq!(ftpentry.unix_owner, Some("root".to_string()));
+        assert_eq!(ftpentry.unix_group, Some("wheel".to_string()));
+        assert_eq!(ftpentry.lang, Some("en".to_string()));
+        assert_eq!(ftpentry.media_type, Some("text/plain".to_string()));
+        assert_eq!(
+            ftpentry.other_facts,
+            vec![("x.custom".to_string(), "1".to_string())]
+        );
+    }
+
+    #[test]
+    fn mlst_symlink_with_leading_space() {
+        let row = " type=OS.unix=slink:/etc/target;size=11; /home/link";
+
+        let ftpentry = FtpEntryMlsd::try_from(row).unwrap();
+
+        assert_eq!(ftpentry.kind(), FtpEntryKind::Symlink);
+        assert_eq!(ftpentry.name(), "/home/link");
+        assert_eq!(ftpentry.date(), None);
+        assert_eq!(ftpentry.date_str(), "");
+        assert_eq!(
+            ftpentry.entry_type,
+            Some(FtpEntryMlsdType::Os(
+                "OS.unix=slink:/etc/target".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn error_invalid_size() {
+        let row = "type=file;size=12a;modify=20120921101300; music.mp3";
+
+        let err = FtpEntry::try_from(row).unwrap_err();
+        assert_eq!(err.format(), Some(FtpEntryFormat::Mlsd));
+        assert_eq!(err.field(), FtpEntryField::Size);
+        assert_eq!(err.offset(), 15);
+    }
+
+    #[test]
+    fn error_missing_name() {
+        let err = FtpEntryMlsd::try_from("type=file;size=12;").unwrap_err();
+        assert_eq!(err.field(), FtpEntryField::Name);
+        assert_eq!(err.offset(), 18);
+    }
+
+    #[test]
+    fn error_invalid_fact() {
+        let err = FtpEntryMlsd::try_from("type=file;size; music.mp3").unwrap_err();
+        assert_eq!(err.field(), FtpEntryField::Fact);
+        assert_eq!(err.offset(), 10);
+    }
+}
@@ -1,3 +1,5 @@
+#![allow(clippy::bool_assert_comparison)]
+
 mod unix {
     use std::convert::TryFrom;
 
@@ -15,6 +17,10 @@ mod unix {
         assert_eq!(ftpentry.name(), "Test");
         assert_eq!(ftpentry.size(), 0);
         assert_eq!(ftpentry.date_str(), "2018-08-22T14:05");
+        assert_eq!(
+            ftpentry.date(),
+            FtpEntryDate::from_ymd_hm(2018, 8, 22, 14, 5)
+        );
         assert_eq!(ftpentry.is_msdos_type(), true);
         assert_eq!(ftpentry.is_unix_type(), false);
     }
@@ -31,6 +37,10 @@ mod unix {
         assert_eq!(ftpentry.name(), "Name []");
         assert_eq!(ftpentry.size(), 0);
         assert_eq!(ftpentry.date_str(), "2018-08-22T14:05");
+        assert_eq!(
+            ftpentry.date(),
+            FtpEntryDate::from_ymd_hm(2018, 8, 22, 14, 5)
+        );
         assert_eq!(ftpentry.is_msdos_type(), true);
         assert_eq!(ftpentry.is_unix_type(), false);
     }
@@ -47,6 +57,10 @@ mod unix {
         assert_eq!(ftpentry.name(), "12");
         assert_eq!(ftpentry.size(), 0);
         assert_eq!(ftpentry.date_str(), "2018-08-22T14:05");
+        assert_eq!(
+            ftpentry.date(),
+            FtpEntryDate::from_ymd_hm(2018, 8, 22, 14, 5)
+        );
         assert_eq!(ftpentry.is_msdos_type(), true);
         assert_eq!(ftpentry.is_unix_type(), false);
     }
@@ -63,6 +77,10 @@ mod unix {
         assert_eq!(ftpentry.name(), "2015");
         assert_eq!(ftpentry.size(), 0);
         assert_eq!(ftpentry.date_str(), "2018-08-22T14:05");
+        assert_eq!(
+            ftpentry.date(),
+            FtpEntryDate::from_ymd_hm(2018, 8, 22, 14, 5)
+        );
         assert_eq!(ftpentry.is_msdos_type(), true);
         assert_eq!(ftpentry.is_unix_type(), false);
     }
@@ -79,6 +97,10 @@ mod unix {
         assert_eq!(ftpentry.name(), "1.1 Header [13]");
         assert_eq!(ftpentry.size(), 0);
         assert_eq!(ftpentry.date_str(), "2018-08-22T14:05");
+        assert_eq!(
+            ftpentry.date(),
+            FtpEntryDate::from_ymd_hm(2018, 8, 22, 14, 5)
+        );
         assert_eq!(ftpentry.is_msdos_type(), true);
         assert_eq!(ftpentry.is_unix_type(), false);
     }
@@ -95,6 +117,10 @@ mod unix {
         assert_eq!(ftpentry.name(), "wwwroot");
         assert_eq!(ftpentry.size(), 0);
         assert_eq!(ftpentry.date_str(), "2018-08-22T14:05");
+        assert_eq!(
+            ftpentry.date(),
+            FtpEntryDate::from_ymd_hm(2018, 8, 22, 14, 5)
+        );
         assert_eq!(ftpentry.is_msdos_type(), true);
         assert_eq!(ftpentry.is_unix_type(), false);
     }
@@ -111,6 +137,10 @@ mod unix {
         assert_eq!(ftpentry.name(), "1400");
         assert_eq!(ftpentry.size(), 0);
         assert_eq!(ftpentry.date_str(), "2013-07-10T06:54");
+        assert_eq!(
+            ftpentry.date(),
+            FtpEntryDate::from_ymd_hm(2013, 7, 10, 6, 54)
+        );
         assert_eq!(ftpentry.is_msdos_type(), true);
         assert_eq!(ftpentry.is_unix_type(), false);
     }
@@ -127,6 +157,10 @@ mod unix {
         assert_eq!(ftpentry.name(), "iisstart.png");
         assert_eq!(ftpentry.size(), 99710);
         assert_eq!(ftpentry.date_str(), "2018-08-22T12:59");
+        assert_eq!(
+            ftpentry.date(),
+            FtpEntryDate::from_ymd_hm(2018, 8, 22, 12, 59)
+        );
         assert_eq!(ftpentry.is_msdos_type(), true);
         assert_eq!(ftpentry.is_unix_type(), false);
     }
@@ -143,6 +177,10 @@ mod unix {
         assert_eq!(ftpentry.name(), "2015");
         assert_eq!(ftpentry.size(), 990);
         assert_eq!(ftpentry.date_str(), "2018-08-22T12:59");
+        assert_eq!(
+            ftpentry.date(),
+            FtpEntryDate::from_ymd_hm(2018, 8, 22, 12, 59)
+        );
         assert_eq!(ftpentry.is_msdos_type(), true);
         assert_eq!(ftpentry.is_unix_type(), false);
     }
@@ -159,7 +197,227 @@ mod unix {
         assert_eq!(ftpentry.name(), "2015");
         assert_eq!(ftpentry.size(), 2015);
         assert_eq!(ftpentry.date_str(), "2018-08-22T12:59");
+        assert_eq!(
+            ftpentry.date(),
+            FtpEntryDate::from_ymd_hm(2018, 8, 22, 12, 59)
+        );
         assert_eq!(ftpentry.is_msdos_type(), true);
         assert_eq!(ftpentry.is_unix_type(), false);
     }
+
+    #[test]
+    fn directory_with_iso_date_and_24_hour_time() {
+        let row = "2018-08-22  14:05       <DIR>          wwwroot";
+
+        let ftpentry = FtpEntry::try_from(row);
+        assert!(ftpentry.is_ok());
+        let ftpentry = ftpentry.unwrap();
+
+        assert_eq!(ftpentry.kind(), FtpEntryKind::Directory);
+        assert_eq!(ftpentry.name(), "wwwroot");
+        assert_eq!(ftpentry.size(), 0);
+        assert_eq!(ftpentry.date_str(), "2018-08-22T14:05");
+        assert_eq!(
+            ftpentry.date(),
+            FtpEntryDate::from_ymd_hm(2018, 8, 22, 14, 5)
+        );
+        assert_eq!(ftpentry.is_msdos_type(), true);
+    }
+
+    #[test]
+    fn file_with_24_hour_time_and_short_date() {
+        let row = "8-2-2018  0:05                  1024 file.txt";
+
+        let ftpentry = FtpEntryMsdos::try_from(row).unwrap();
+
+        assert_eq!(ftpentry.kind(), FtpEntryKind::File);
+        assert_eq!(ftpentry.name(), "file.txt");
+        assert_eq!(ftpentry.size(), 1024);
+        assert_eq!(ftpentry.date_str(), "2018-08-02T00:05");
+    }
+
+    #[test]
+    fn day_month_order() {
+        let row = "22.08.18  14:05                 1024 file.txt";
+
+        assert!(FtpEntryMsdos::try_from(row).is_err());
+
+        let ftpentry =
+            FtpEntryMsdos::parse_with_date_order(row, FtpEntryDateOrder::DayMonth).unwrap();
+
+        assert_eq!(ftpentry.date_str(), "2018-08-22T14:05");
+        assert_eq!(
+            ftpentry.date(),
+            FtpEntryDate::from_ymd_hm(2018, 8, 22, 14, 5)
+        );
+
+        // Dates with the year first do not depend on the order.
+        let row = "2018-08-22  14:05               1024 file.txt";
+        let ftpentry =
+            FtpEntryMsdos::parse_with_date_order(row, FtpEntryDateOrder::DayMonth).unwrap();
+
+        assert_eq!(
+            ftpentry.date(),
+            FtpEntryDate::from_ymd_hm(2018, 8, 22, 14, 5)
+        );
+
+        let listing = "05-08-18  14:05       <DIR>          Test\r\n";
+        let listing = FtpListing::parse_with_date_order(listing, FtpEntryDateOrder::DayMonth);
+
+        assert_eq!(
+            listing.entries[0].date(),
+            FtpEntryDate::from_ymd_hm(2018, 8, 5, 14, 5)
+        );
+    }
+
+    #[test]
+    fn error_invalid_24_hour_time() {
+        let row = "08-22-18  24:05                 1024 file.txt";
+
+        let err = FtpEntryMsdos::try_from(row).unwrap_err();
+        assert_eq!(err.field(), FtpEntryField::Timestamp);
+        assert_eq!(err.offset(), 10);
+    }
+
+    #[test]
+    fn junction_with_target() {
+        let row = r"08-22-18  02:05PM    <JUNCTION>     Documents and Settings [C:\Users]";
+
+        let ftpentry = FtpEntry::try_from(row);
+        assert!(ftpentry.is_ok());
+        let ftpentry = ftpentry.unwrap();
+
+        assert_eq!(ftpentry.kind(), FtpEntryKind::Symlink);
+        assert_eq!(ftpentry.name(), "Documents and Settings");
+        assert_eq!(ftpentry.size(), 0);
+        assert_eq!(ftpentry.is_msdos_type(), true);
+
+        let ftpentry_msdos = ftpentry.to_msdos_type();
+
+        assert_eq!(ftpentry_msdos.target.as_deref(), Some(r"C:\Users"));
+    }
+
+    #[test]
+    fn symlinks_with_and_without_target() {
+        let row = r"2018-08-22  14:05    <SYMLINKD>     logs [..\var\logs]";
+        let ftpentry = FtpEntryMsdos::try_from(row).unwrap();
+
+        assert_eq!(ftpentry.kind(), FtpEntryKind::Symlink);
+        assert_eq!(ftpentry.name(), "logs");
+        assert_eq!(ftpentry.target.as_deref(), Some(r"..\var\logs"));
+
+        let row = "2018-08-22  14:05    <SYMLINK>      config.lnk";
+        let ftpentry = FtpEntryMsdos::try_from(row).unwrap();
+
+        assert_eq!(ftpentry.kind(), FtpEntryKind::Symlink);
+        assert_eq!(ftpentry.name(), "config.lnk");
+        assert_eq!(ftpentry.target, None);
+
+        // Only links have targets, brackets in other names are kept.
+        let row = "08-22-18  02:05pm       <DIR>          Name [old]";
+        let ftpentry = FtpEntryMsdos::try_from(row).unwrap();
+
+        assert_eq!(ftpentry.name(), "Name [old]");
+        assert_eq!(ftpentry.target, None);
+    }
+
+    #[test]
+    fn borrowed_junction() {
+        let row = "08-22-18  02:05PM    <JUNCTION>        Documents and Settings [C:\\Users]";
+        let entry = FtpEntryMsdosRef::try_from(row).unwrap();
+
+        assert_eq!(entry.kind(), FtpEntryKind::Symlink);
+        assert_eq!(entry.name(), "Documents and Settings");
+        assert_eq!(entry.target, Some("C:\\Users"));
+        assert_eq!(entry.date_str(), "2018-08-22T14:05");
+        assert!(std::ptr::eq(entry.name(), &row[39..61]));
+
+        let owned = entry.to_owned();
+        assert_eq!(owned.name(), "Documents and Settings");
+        assert_eq!(owned.target.as_deref(), Some("C:\\Users"));
+    }
+
+    #[test]
+    fn file_with_grouped_size() {
+        let row = "08-22-18  12:59PM            1,234,567 backup.zip";
+
+        let ftpentry = FtpEntry::try_from(row);
+        assert!(ftpentry.is_ok());
+        let ftpentry = ftpentry.unwrap();
+
+        assert_eq!(ftpentry.kind(), FtpEntryKind::File);
+        assert_eq!(ftpentry.name(), "backup.zip");
+        assert_eq!(ftpentry.size(), 1_234_567);
+        assert_eq!(ftpentry.is_msdos_type(), true);
+
+        for row in [
+            "22.08.18  14:05               1.234.567 backup.zip",
+            "22.08.18  14:05               1'234'567 backup.zip",
+            "22.08.18  14:05               1\u{a0}234\u{a0}567 backup.zip",
+            "22.08.18  14:05               1\u{202f}234\u{202f}567 backup.zip",
+        ] {
+            let ftpentry =
+                FtpEntryMsdos::parse_with_date_order(row, FtpEntryDateOrder::DayMonth).unwrap();
+
+            assert_eq!(ftpentry.name(), "backup.zip");
+            assert_eq!(ftpentry.size(), 1_234_567);
+        }
+    }
+
+    #[test]
+    fn file_with_grouped_size_like_name() {
+        // Digits after the size are a part of the name, not another group.
+        let row = "08-22-18  12:59PM                  999 710.txt";
+        let ftpentry = FtpEntryMsdos::try_from(row).unwrap();
+
+        assert_eq!(ftpentry.name(), "710.txt");
+        assert_eq!(ftpentry.size(), 999);
+    }
+
+    #[test]
+    fn error_mixed_size_separators() {
+        let row = "08-22-18  12:59PM            1,234.567 backup.zip";
+
+        let err = FtpEntryMsdos::try_from(row).unwrap_err();
+        assert_eq!(err.format(), Some(FtpEntryFormat::Msdos));
+        assert_eq!(err.field(), FtpEntryField::Size);
+        assert_eq!(err.offset(), 29);
+    }
+
+    #[test]
+    fn error_size_overflow() {
+        let row = "08-22-18  12:59PM   18,446,744,073,709,551,616 backup.zip";
+
+        let err = FtpEntry::try_from(row).unwrap_err();
+        assert_eq!(err.format(), Some(FtpEntryFormat::Msdos));
+        assert_eq!(err.field(), FtpEntryField::Size);
+        assert_eq!(err.offset(), 20);
+    }
+
+    #[test]
+    fn error_invalid_date() {
+        let row = "13-22-18  12:59PM                2015 2015";
+
+        let err = FtpEntry::try_from(row).unwrap_err();
+        assert_eq!(err.format(), Some(FtpEntryFormat::Msdos));
+        assert_eq!(err.field(), FtpEntryField::Timestamp);
+        assert_eq!(err.offset(), 0);
+
+        let row = "08-22-18  12:75PM                2015 2015";
+
+        let err = FtpEntryMsdos::try_from(row).unwrap_err();
+        assert_eq!(err.field(), FtpEntryField::Timestamp);
+        assert_eq!(err.offset(), 10);
+    }
+
+    #[test]
+    fn error_invalid_size() {
+        let row = "08-22-18  12:59PM       <FILE>          2015";
+
+        let err = FtpEntryMsdos::try_from(row).unwrap_err();
+        assert_eq!(err.format(), Some(FtpEntryFormat::Msdos));
+        assert_eq!(err.field(), FtpEntryField::Size);
+        assert_eq!(err.offset(), 24);
+        assert_eq!(err.to_string(), "msdos entry: invalid size at byte 24");
+    }
 }
@@ -0,0 +1,172 @@
+#![allow(clippy::bool_assert_comparison)]
+
+mod mvs {
+    use std::convert::TryFrom;
+
+    use ::ftp_cmd_list_parse::*;
+
+    #[test]
+    fn sequential_dataset() {
+        let row = "WYOSPT 3420   2003/05/21  1  200  FB      80  8053  PS  BPL.CICS.ONLINE";
+
+        let ftpentry = FtpEntry::try_from(row);
+        assert!(ftpentry.is_ok());
+        let ftpentry = ftpentry.unwrap();
+
+        assert_eq!(ftpentry.kind(), FtpEntryKind::File);
+        assert_eq!(ftpentry.name(), "BPL.CICS.ONLINE");
+        assert_eq!(ftpentry.size(), 0);
+        assert_eq!(ftpentry.date_str(), "2003/05/21");
+        assert_eq!(ftpentry.date(), FtpEntryDate::from_ymd(2003, 5, 21));
+        assert_eq!(ftpentry.is_mvs_dataset_type(), true);
+        assert_eq!(ftpentry.is_mvs_member_type(), false);
+
+        let ftpentry_mvs = ftpentry.to_mvs_dataset_type();
+
+        assert_eq!(ftpentry_mvs.volume.as_deref(), Some("WYOSPT"));
+        assert_eq!(ftpentry_mvs.unit.as_deref(), Some("3420"));
+        assert_eq!(ftpentry_mvs.extents, Some(1));
+        assert_eq!(ftpentry_mvs.used, Some(200));
+        assert_eq!(ftpentry_mvs.recfm.as_deref(), Some("FB"));
+        assert_eq!(ftpentry_mvs.lrecl, Some(80));
+        assert_eq!(ftpentry_mvs.blksize, Some(8053));
+        assert_eq!(ftpentry_mvs.dsorg.as_deref(), Some("PS"));
+        assert_eq!(ftpentry_mvs.migrated, false);
+    }
+
+    #[test]
+    fn partitioned_and_vsam_datasets() {
+        let row = "SMS164 3390   **NONE**    1   15  U        ?  6144  PO  USER.LOADLIB";
+        let ftpentry = FtpEntryMvsDataset::try_from(row).unwrap();
+
+        assert_eq!(ftpentry.kind(), FtpEntryKind::Directory);
+        assert_eq!(ftpentry.date(), None);
+        assert_eq!(ftpentry.date_str(), "**NONE**");
+        assert_eq!(ftpentry.lrecl, None);
+        assert_eq!(ftpentry.blksize, Some(6144));
+
+        let row = "SMS164 3390   2020/03/04  1    1  ?         ?     ?  VSAM USER.KSDS.DATA";
+        let ftpentry = FtpEntryMvsDataset::try_from(row).unwrap();
+
+        assert_eq!(ftpentry.kind(), FtpEntryKind::File);
+        assert_eq!(ftpentry.name(), "USER.KSDS.DATA");
+        assert_eq!(ftpentry.recfm, None);
+        assert_eq!(ftpentry.lrecl, None);
+        assert_eq!(ftpentry.blksize, None);
+        assert_eq!(ftpentry.dsorg.as_deref(), Some("VSAM"));
+    }
+
+    #[test]
+    fn migrated_and_pseudo_directory() {
+        let row = "Migrated                                                OLD.DATASET";
+        let ftpentry = FtpEntryMvsDataset::try_from(row).unwrap();
+
+        assert_eq!(ftpentry.kind(), FtpEntryKind::File);
+        assert_eq!(ftpentry.name(), "OLD.DATASET");
+        assert_eq!(ftpentry.migrated, true);
+        assert_eq!(ftpentry.volume, None);
+        assert_eq!(ftpentry.date(), None);
+
+        let row = "Pseudo Directory                                        USER.PROJECT";
+        let ftpentry = FtpEntryMvsDataset::try_from(row).unwrap();
+
+        assert_eq!(ftpentry.kind(), FtpEntryKind::Directory);
+        assert_eq!(ftpentry.name(), "USER.PROJECT");
+        assert_eq!(ftpentry.migrated, false);
+
+        let row = "ARCIVE Not Direct Access Device                         USER.TAPE.BACKUP";
+        let ftpentry = FtpEntryMvsDataset::try_from(row).unwrap();
+
+        assert_eq!(ftpentry.name(), "USER.TAPE.BACKUP");
+        assert_eq!(ftpentry.volume.as_deref(), Some("ARCIVE"));
+    }
+
+    #[test]
+    fn member() {
+        let row = "MEMBER1   01.03 2002/09/10 2002/09/12 16:09    15    12     3 USERID";
+
+        let ftpentry = FtpEntry::try_from(row);
+        assert!(ftpentry.is_ok());
+        let ftpentry = ftpentry.unwrap();
+
+        assert_eq!(ftpentry.kind(), FtpEntryKind::File);
+        assert_eq!(ftpentry.name(), "MEMBER1");
+        assert_eq!(ftpentry.date_str(), "2002/09/12 16:09");
+        assert_eq!(
+            ftpentry.date(),
+            FtpEntryDate::from_ymd_hm(2002, 9, 12, 16, 9)
+        );
+        assert_eq!(ftpentry.is_mvs_member_type(), true);
+
+        let ftpentry_mvs = ftpentry.to_mvs_member_type();
+
+        assert_eq!(ftpentry_mvs.version, Some((1, 3)));
+        assert_eq!(ftpentry_mvs.created, FtpEntryDate::from_ymd(2002, 9, 10));
+        assert_eq!(ftpentry_mvs.records, Some(15));
+        assert_eq!(ftpentry_mvs.init, Some(12));
+        assert_eq!(ftpentry_mvs.modified, Some(3));
+        assert_eq!(ftpentry_mvs.id.as_deref(), Some("USERID"));
+    }
+
+    #[test]
+    fn invalid_dataset() {
+        let row = "WYOSPT 3420   2003/05/21  1  200  FB      80";
+        let err = FtpEntryMvsDataset::try_from(row).unwrap_err();
+
+        assert_eq!(err.format(), Some(FtpEntryFormat::Mvs));
+        assert_eq!(err.field(), FtpEntryField::RecordFormat);
+        assert_eq!(err.offset(), 34);
+
+        let row = "WYOSPT 3420   2003/13/21  1  200  FB      80  8053  PS  BPL.CICS.ONLINE";
+        let err = FtpEntryMvsDataset::try_from(row).unwrap_err();
+
+        assert_eq!(err.field(), FtpEntryField::Timestamp);
+        assert_eq!(err.offset(), 14);
+    }
+
+    #[test]
+    fn garbage_is_not_reported_as_mvs() {
+        for row in &["garbage", "this is not an entry"] {
+            let err = FtpEntry::try_from(*row).unwrap_err();
+            assert_ne!(err.format(), Some(FtpEntryFormat::Mvs), "{}", row);
+        }
+
+        let err = FtpEntryMvsMember::try_from("garbage").unwrap_err();
+        assert_eq!(err.format(), Some(FtpEntryFormat::Mvs));
+        assert_eq!(err.field(), FtpEntryField::Version);
+        assert_eq!(err.offset(), 7);
+    }
+
+    #[test]
+    fn listings_with_headers() {
+        let listing = "Volume Unit    Referred Ext Used Recfm Lrecl BlkSz Dsorg Dsname\r\n\
+            WYOSPT 3420   2003/05/21  1  200  FB      80  8053  PS  BPL.CICS.ONLINE\r\n\
+            Migrated                                                OLD.DATASET\r\n";
+
+        let listing = FtpListing::parse(listing);
+
+        assert_eq!(listing.entries.len(), 2);
+        assert_eq!(listing.entries[0].is_mvs_dataset_type(), true);
+        assert_eq!(listing.entries[1].name(), "OLD.DATASET");
+        assert_eq!(listing.unparsed, vec![]);
+
+        let listing = " Name     VV.MM   Created       Changed      Size  Init   Mod   Id\r\n\
+            MEMBER1   01.01 2002/09/10 2002/09/10 16:09    15    15     0 USERID\r\n\
+            MEMBER2\r\n";
+
+        let listing = FtpListing::parse(listing);
+
+        assert_eq!(listing.entries.len(), 2);
+        assert_eq!(listing.entries[0].name(), "MEMBER1");
+        assert_eq!(listing.entries[1].name(), "MEMBER2");
+        assert_eq!(listing.entries[1].is_mvs_member_type(), true);
+        assert_eq!(listing.entries[1].date(), None);
+        assert_eq!(listing.unparsed, vec![]);
+
+        // Bare names are not members without the header.
+        let listing = FtpListing::parse("MEMBER2\r\n");
+
+        assert_eq!(listing.entries.len(), 0);
+        assert_eq!(listing.unparsed.len(), 1);
+    }
+}
@@ -0,0 +1,71 @@
+#![allow(clippy::bool_assert_comparison)]
+
+mod netware {
+    use std::convert::TryFrom;
+
+    use ::ftp_cmd_list_parse::*;
+
+    #[test]
+    fn directory() {
+        let row = "d [RWCEAFMS] owner                512 Mar 03 14:04 dirname";
+
+        let ftpentry = FtpEntry::try_from(row);
+        assert!(ftpentry.is_ok());
+        let ftpentry = ftpentry.unwrap();
+
+        assert_eq!(ftpentry.kind(), FtpEntryKind::Directory);
+        assert_eq!(ftpentry.name(), "dirname");
+        assert_eq!(ftpentry.size(), 512);
+        assert_eq!(ftpentry.date_str(), "Mar 03 14:04");
+        assert_eq!(ftpentry.date(), FtpEntryDate::from_md_hm(3, 3, 14, 4));
+        assert_eq!(ftpentry.is_netware_type(), true);
+        assert_eq!(ftpentry.is_unix_type(), false);
+
+        let ftpentry_netware = ftpentry.to_netware_type();
+
+        assert_eq!(ftpentry_netware.owner, "owner");
+        assert_eq!(
+            ftpentry_netware.rights,
+            FtpEntryNetwareRights {
+                supervisor: true,
+                read: true,
+                write: true,
+                create: true,
+                erase: true,
+                modify: true,
+                file_scan: true,
+                access_control: true,
+            }
+        );
+    }
+
+    #[test]
+    fn file_with_partial_rights() {
+        let row = "- [R----F--] jdoe              1024 Dec 21  2012 file name.txt";
+        let ftpentry = FtpEntryNetware::try_from(row).unwrap();
+
+        assert_eq!(ftpentry.kind(), FtpEntryKind::File);
+        assert_eq!(ftpentry.name(), "file name.txt");
+        assert_eq!(ftpentry.size(), 1024);
+        assert_eq!(ftpentry.date(), FtpEntryDate::from_ymd(2012, 12, 21));
+        assert_eq!(ftpentry.owner, "jdoe");
+        assert_eq!(
+            ftpentry.rights,
+            FtpEntryNetwareRights {
+                read: true,
+                file_scan: true,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn invalid_rights() {
+        let row = "d [RWXEAFMS] owner                512 Mar 03 14:04 dirname";
+        let err = FtpEntryNetware::try_from(row).unwrap_err();
+
+        assert_eq!(err.format(), Some(FtpEntryFormat::Netware));
+        assert_eq!(err.field(), FtpEntryField::Permissions);
+        assert_eq!(err.offset(), 2);
+    }
+}
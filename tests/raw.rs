@@ -0,0 +1,57 @@
+#![allow(clippy::bool_assert_comparison)]
+
+mod raw {
+    use ::ftp_cmd_list_parse::*;
+
+    #[test]
+    fn utf8_line() {
+        let line = "-rw-r--r--   1 owner   group    1024 Sep 02  2012 Привет.txt";
+        let raw = FtpEntry::try_from_bytes(line.as_bytes()).unwrap();
+
+        assert_eq!(raw.entry.name(), "Привет.txt");
+        assert_eq!(raw.raw_name, "Привет.txt".as_bytes());
+    }
+
+    #[test]
+    fn latin1_line() {
+        let line = b"08-22-18  12:59PM                99710 \xcf\xf0\xe8\xe2\xe5\xf2.txt";
+        let raw = FtpEntry::try_from_bytes(line).unwrap();
+
+        assert_eq!(raw.entry.is_msdos_type(), true);
+        assert_eq!(raw.entry.name(), "\u{cf}\u{f0}\u{e8}\u{e2}\u{e5}\u{f2}.txt");
+        assert_eq!(raw.raw_name, b"\xcf\xf0\xe8\xe2\xe5\xf2.txt");
+    }
+
+    #[test]
+    fn error_offset_in_raw_bytes() {
+        let line = b"-rw-r--r--   1 \xe9\xe9\xe9   group    1024 Dez 02  2012 file.txt";
+
+        let err = FtpEntry::try_from_bytes(line).unwrap_err();
+        assert_eq!(err.format(), Some(FtpEntryFormat::Unix));
+        assert_eq!(err.field(), FtpEntryField::Timestamp);
+        assert_eq!(err.offset(), 35);
+    }
+
+    #[cfg(feature = "encoding")]
+    #[test]
+    fn shift_jis_line() {
+        let encoding = Encoding::for_label(b"shift_jis").unwrap();
+        let (line, _, _) =
+            encoding.encode("-rw-r--r--   1 owner   group    1024 Sep 02  2012 日本語.txt");
+        let raw = FtpEntry::try_from_bytes_with_encoding(&line, encoding).unwrap();
+
+        assert_eq!(raw.entry.name(), "日本語.txt");
+        assert_eq!(raw.raw_name, encoding.encode("日本語.txt").0.as_ref());
+    }
+
+    #[cfg(feature = "encoding")]
+    #[test]
+    fn malformed_line_falls_back_to_latin1() {
+        let encoding = Encoding::for_label(b"utf-8").unwrap();
+        let line = b"-rw-r--r--   1 owner   group    1024 Sep 02  2012 caf\xe9.txt";
+        let raw = FtpEntry::try_from_bytes_with_encoding(line, encoding).unwrap();
+
+        assert_eq!(raw.entry.name(), "caf\u{e9}.txt");
+        assert_eq!(raw.raw_name, b"caf\xe9.txt");
+    }
+}
@@ -1,8 +1,18 @@
+#![allow(clippy::bool_assert_comparison)]
+
 mod unix {
-    use std::convert::TryFrom;
+    use std::{
+        convert::TryFrom,
+        time::{Duration, SystemTime, UNIX_EPOCH},
+    };
 
     use ::ftp_cmd_list_parse::*;
 
+    /// 2020-06-01T00:00:00Z
+    fn now() -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(1_590_969_600)
+    }
+
     #[test]
     fn normal_directory() {
         let row = "drwxr-xr-x  10 root   root    4096 Dec 21  2012 usr";
@@ -15,10 +25,7 @@ mod unix {
         assert_eq!(ftpentry.name(), "usr");
         assert_eq!(ftpentry.size(), 4096);
         assert_eq!(ftpentry.date_str(), "Dec 21 2012");
-        // assert_eq!(
-        //     ftpentry.date(),
-        //     NaiveDate::from_ymd(2012, 12, 21).and_hms(0, 0, 0)
-        // );
+        assert_eq!(ftpentry.date(), FtpEntryDate::from_ymd(2012, 12, 21));
 
         assert_eq!(ftpentry.is_msdos_type(), false);
         assert_eq!(ftpentry.is_unix_type(), true);
@@ -28,9 +35,10 @@ mod unix {
         assert_eq!(ftpentry_unix.target, None);
         assert_eq!(ftpentry_unix.sticky, false);
         assert_eq!(ftpentry_unix.permissions.as_str(), "rwxr-xr-x");
+        assert_eq!(ftpentry_unix.link_count, 10);
         assert_eq!(ftpentry_unix.acl, false);
-        assert_eq!(ftpentry_unix.owner, "root");
-        assert_eq!(ftpentry_unix.group, "root");
+        assert_eq!(ftpentry_unix.owner.as_deref(), Some("root"));
+        assert_eq!(ftpentry_unix.group.as_deref(), Some("root"));
         assert_eq!(ftpentry_unix.pointer, None);
     }
 
@@ -46,10 +54,7 @@ mod unix {
         assert_eq!(ftpentry.name(), "e-books");
         assert_eq!(ftpentry.size(), 0);
         assert_eq!(ftpentry.date_str(), "Aug 31 2012");
-        // assert_eq!(
-        //     ftpentry.date(),
-        //     NaiveDate::from_ymd(2012, 8, 31).and_hms(0, 0, 0)
-        // );
+        assert_eq!(ftpentry.date(), FtpEntryDate::from_ymd(2012, 8, 31));
 
         assert_eq!(ftpentry.is_msdos_type(), false);
         assert_eq!(ftpentry.is_unix_type(), true);
@@ -60,8 +65,8 @@ mod unix {
         assert_eq!(ftpentry_unix.sticky, false);
         assert_eq!(ftpentry_unix.permissions.as_str(), "rwxrwxrwx");
         assert_eq!(ftpentry_unix.acl, false);
-        assert_eq!(ftpentry_unix.owner, "owner");
-        assert_eq!(ftpentry_unix.group, "group");
+        assert_eq!(ftpentry_unix.owner.as_deref(), Some("owner"));
+        assert_eq!(ftpentry_unix.group.as_deref(), Some("group"));
         assert_eq!(ftpentry_unix.pointer, None);
     }
 
@@ -77,10 +82,7 @@ mod unix {
         assert_eq!(ftpentry.name(), "music.mp3");
         assert_eq!(ftpentry.size(), 7045120);
         assert_eq!(ftpentry.date_str(), "Sep 02 2012");
-        // assert_eq!(
-        //     ftpentry.date(),
-        //     NaiveDate::from_ymd(2012, 9, 2).and_hms(0, 0, 0)
-        // );
+        assert_eq!(ftpentry.date(), FtpEntryDate::from_ymd(2012, 9, 2));
 
         assert_eq!(ftpentry.is_msdos_type(), false);
         assert_eq!(ftpentry.is_unix_type(), true);
@@ -91,8 +93,8 @@ mod unix {
         assert_eq!(ftpentry_unix.sticky, false);
         assert_eq!(ftpentry_unix.permissions.as_str(), "rw-rw-rw-");
         assert_eq!(ftpentry_unix.acl, false);
-        assert_eq!(ftpentry_unix.owner, "owner");
-        assert_eq!(ftpentry_unix.group, "group");
+        assert_eq!(ftpentry_unix.owner.as_deref(), Some("owner"));
+        assert_eq!(ftpentry_unix.group.as_deref(), Some("group"));
         assert_eq!(ftpentry_unix.pointer, None);
     }
 
@@ -108,10 +110,7 @@ mod unix {
         assert_eq!(ftpentry.name(), "music.mp3");
         assert_eq!(ftpentry.size(), 7045120);
         assert_eq!(ftpentry.date_str(), "Sep 02 2012");
-        // assert_eq!(
-        //     ftpentry.date(),
-        //     NaiveDate::from_ymd(2012, 9, 2).and_hms(0, 0, 0)
-        // );
+        assert_eq!(ftpentry.date(), FtpEntryDate::from_ymd(2012, 9, 2));
 
         assert_eq!(ftpentry.is_msdos_type(), false);
         assert_eq!(ftpentry.is_unix_type(), true);
@@ -122,8 +121,8 @@ mod unix {
         assert_eq!(ftpentry_unix.sticky, false);
         assert_eq!(ftpentry_unix.permissions.as_str(), "rw-rw-rw-");
         assert_eq!(ftpentry_unix.acl, false);
-        assert_eq!(ftpentry_unix.owner, "1234");
-        assert_eq!(ftpentry_unix.group, "group");
+        assert_eq!(ftpentry_unix.owner.as_deref(), Some("1234"));
+        assert_eq!(ftpentry_unix.group.as_deref(), Some("group"));
         assert_eq!(ftpentry_unix.pointer, None);
     }
 
@@ -139,10 +138,7 @@ mod unix {
         assert_eq!(ftpentry.name(), "music.mp3");
         assert_eq!(ftpentry.size(), 7045120);
         assert_eq!(ftpentry.date_str(), "Sep 02 2012");
-        // assert_eq!(
-        //     ftpentry.date(),
-        //     NaiveDate::from_ymd(2012, 9, 2).and_hms(0, 0, 0)
-        // );
+        assert_eq!(ftpentry.date(), FtpEntryDate::from_ymd(2012, 9, 2));
 
         assert_eq!(ftpentry.is_msdos_type(), false);
         assert_eq!(ftpentry.is_unix_type(), true);
@@ -153,8 +149,8 @@ mod unix {
         assert_eq!(ftpentry_unix.sticky, false);
         assert_eq!(ftpentry_unix.permissions.as_str(), "rw-rw-rw-");
         assert_eq!(ftpentry_unix.acl, false);
-        assert_eq!(ftpentry_unix.owner, "owner");
-        assert_eq!(ftpentry_unix.group, "1234");
+        assert_eq!(ftpentry_unix.owner.as_deref(), Some("owner"));
+        assert_eq!(ftpentry_unix.group.as_deref(), Some("1234"));
         assert_eq!(ftpentry_unix.pointer, None);
     }
 
@@ -171,11 +167,11 @@ mod unix {
         assert_eq!(ftpentry.name(), "xmlrpc.php");
         assert_eq!(ftpentry.size(), 3065);
         assert_eq!(ftpentry.date_str(), "May 4 11:01");
-        //TODO: checks for correct year
-        // assert_eq!(
-        //     ftpentry.date(),
-        //     NaiveDate::from_ymd(ftpentry.date().year(), 5, 4).and_hms(11, 1, 0)
-        // );
+        assert_eq!(ftpentry.date(), FtpEntryDate::from_md_hm(5, 4, 11, 1));
+        assert_eq!(
+            ftpentry.date().unwrap().infer_year(now()).year(),
+            Some(2020)
+        );
 
         assert_eq!(ftpentry.is_msdos_type(), false);
         assert_eq!(ftpentry.is_unix_type(), true);
@@ -186,8 +182,8 @@ mod unix {
         assert_eq!(ftpentry_unix.sticky, false);
         assert_eq!(ftpentry_unix.permissions.as_str(), "rwxrwxr-x");
         assert_eq!(ftpentry_unix.acl, false);
-        assert_eq!(ftpentry_unix.owner, "1317");
-        assert_eq!(ftpentry_unix.group, "Domain Use");
+        assert_eq!(ftpentry_unix.owner.as_deref(), Some("1317"));
+        assert_eq!(ftpentry_unix.group.as_deref(), Some("Domain Use"));
         assert_eq!(ftpentry_unix.pointer, None);
     }
 
@@ -204,11 +200,11 @@ mod unix {
         assert_eq!(ftpentry.name(), "xmlrpc.php");
         assert_eq!(ftpentry.size(), 3065);
         assert_eq!(ftpentry.date_str(), "May 4 11:01");
-        //TODO: checks for correct year
-        // assert_eq!(
-        //     ftpentry.date(),
-        //     NaiveDate::from_ymd(ftpentry.date().year(), 5, 4).and_hms(11, 1, 0)
-        // );
+        assert_eq!(ftpentry.date(), FtpEntryDate::from_md_hm(5, 4, 11, 1));
+        assert_eq!(
+            ftpentry.date().unwrap().infer_year(now()).year(),
+            Some(2020)
+        );
 
         assert_eq!(ftpentry.is_msdos_type(), false);
         assert_eq!(ftpentry.is_unix_type(), true);
@@ -219,8 +215,8 @@ mod unix {
         assert_eq!(ftpentry_unix.sticky, false);
         assert_eq!(ftpentry_unix.permissions.as_str(), "rwxrwxr-x");
         assert_eq!(ftpentry_unix.acl, false);
-        assert_eq!(ftpentry_unix.owner, "1317");
-        assert_eq!(ftpentry_unix.group, "Domain  Use");
+        assert_eq!(ftpentry_unix.owner.as_deref(), Some("1317"));
+        assert_eq!(ftpentry_unix.group.as_deref(), Some("Domain  Use"));
         assert_eq!(ftpentry_unix.pointer, None);
     }
 
@@ -236,11 +232,11 @@ mod unix {
         assert_eq!(ftpentry.name(), "xmlrpc.php");
         assert_eq!(ftpentry.size(), 3065);
         assert_eq!(ftpentry.date_str(), "May 4 11:01");
-        //TODO: checks for correct year
-        // assert_eq!(
-        //     ftpentry.date(),
-        //     NaiveDate::from_ymd(ftpentry.date().year(), 5, 4).and_hms(11, 1, 0)
-        // );
+        assert_eq!(ftpentry.date(), FtpEntryDate::from_md_hm(5, 4, 11, 1));
+        assert_eq!(
+            ftpentry.date().unwrap().infer_year(now()).year(),
+            Some(2020)
+        );
 
         assert_eq!(ftpentry.is_msdos_type(), false);
         assert_eq!(ftpentry.is_unix_type(), true);
@@ -251,8 +247,8 @@ mod unix {
         assert_eq!(ftpentry_unix.sticky, false);
         assert_eq!(ftpentry_unix.permissions.as_str(), "rwxrwxr-x");
         assert_eq!(ftpentry_unix.acl, false);
-        assert_eq!(ftpentry_unix.owner, "Domain Use");
-        assert_eq!(ftpentry_unix.group, "33");
+        assert_eq!(ftpentry_unix.owner.as_deref(), Some("Domain Use"));
+        assert_eq!(ftpentry_unix.group.as_deref(), Some("33"));
         assert_eq!(ftpentry_unix.pointer, None);
     }
 
@@ -269,11 +265,11 @@ mod unix {
         assert_eq!(ftpentry.name(), "xmlrpc.php");
         assert_eq!(ftpentry.size(), 3065);
         assert_eq!(ftpentry.date_str(), "May 4 11:01");
-        //TODO: checks for correct year
-        // assert_eq!(
-        //     ftpentry.date(),
-        //     NaiveDate::from_ymd(ftpentry.date().year(), 5, 4).and_hms(11, 1, 0)
-        // );
+        assert_eq!(ftpentry.date(), FtpEntryDate::from_md_hm(5, 4, 11, 1));
+        assert_eq!(
+            ftpentry.date().unwrap().infer_year(now()).year(),
+            Some(2020)
+        );
 
         assert_eq!(ftpentry.is_msdos_type(), false);
         assert_eq!(ftpentry.is_unix_type(), true);
@@ -284,8 +280,8 @@ mod unix {
         assert_eq!(ftpentry_unix.sticky, false);
         assert_eq!(ftpentry_unix.permissions.as_str(), "rwxrwxr-x");
         assert_eq!(ftpentry_unix.acl, false);
-        assert_eq!(ftpentry_unix.owner, "Domain  Use");
-        assert_eq!(ftpentry_unix.group, "33");
+        assert_eq!(ftpentry_unix.owner.as_deref(), Some("Domain  Use"));
+        assert_eq!(ftpentry_unix.group.as_deref(), Some("33"));
         assert_eq!(ftpentry_unix.pointer, None);
     }
 
@@ -301,11 +297,11 @@ mod unix {
         assert_eq!(ftpentry.name(), ".ftpquota");
         assert_eq!(ftpentry.size(), 14);
         assert_eq!(ftpentry.date_str(), "May 15 01:52");
-        //TODO: checks for correct year
-        // assert_eq!(
-        //     ftpentry.date(),
-        //     NaiveDate::from_ymd(ftpentry.date().year(), 5, 15).and_hms(1, 52, 0)
-        // );
+        assert_eq!(ftpentry.date(), FtpEntryDate::from_md_hm(5, 15, 1, 52));
+        assert_eq!(
+            ftpentry.date().unwrap().infer_year(now()).year(),
+            Some(2020)
+        );
 
         assert_eq!(ftpentry.is_msdos_type(), false);
         assert_eq!(ftpentry.is_unix_type(), true);
@@ -316,8 +312,8 @@ mod unix {
         assert_eq!(ftpentry_unix.sticky, false);
         assert_eq!(ftpentry_unix.permissions.as_str(), "rw-------");
         assert_eq!(ftpentry_unix.acl, false);
-        assert_eq!(ftpentry_unix.owner, "33");
-        assert_eq!(ftpentry_unix.group, "www-data");
+        assert_eq!(ftpentry_unix.owner.as_deref(), Some("33"));
+        assert_eq!(ftpentry_unix.group.as_deref(), Some("www-data"));
         assert_eq!(ftpentry_unix.pointer, None);
     }
 
@@ -333,11 +329,11 @@ mod unix {
         assert_eq!(ftpentry.name(), ".ftpquota");
         assert_eq!(ftpentry.size(), 14);
         assert_eq!(ftpentry.date_str(), "May 15 01:52");
-        //TODO: checks for correct year
-        // assert_eq!(
-        //     ftpentry.date(),
-        //     NaiveDate::from_ymd(ftpentry.date().year(), 5, 15).and_hms(1, 52, 0)
-        // );
+        assert_eq!(ftpentry.date(), FtpEntryDate::from_md_hm(5, 15, 1, 52));
+        assert_eq!(
+            ftpentry.date().unwrap().infer_year(now()).year(),
+            Some(2020)
+        );
 
         assert_eq!(ftpentry.is_msdos_type(), false);
         assert_eq!(ftpentry.is_unix_type(), true);
@@ -348,8 +344,8 @@ mod unix {
         assert_eq!(ftpentry_unix.sticky, false);
         assert_eq!(ftpentry_unix.permissions.as_str(), "rw-------");
         assert_eq!(ftpentry_unix.acl, false);
-        assert_eq!(ftpentry_unix.owner, "www-data");
-        assert_eq!(ftpentry_unix.group, "33");
+        assert_eq!(ftpentry_unix.owner.as_deref(), Some("www-data"));
+        assert_eq!(ftpentry_unix.group.as_deref(), Some("33"));
         assert_eq!(ftpentry_unix.pointer, None);
     }
 
@@ -365,10 +361,7 @@ mod unix {
         assert_eq!(ftpentry.name(), "music.mp3");
         assert_eq!(ftpentry.size(), 7045120);
         assert_eq!(ftpentry.date_str(), "Sep 02 2012");
-        // assert_eq!(
-        //     ftpentry.date(),
-        //     NaiveDate::from_ymd(2012, 9, 2).and_hms(0, 0, 0)
-        // );
+        assert_eq!(ftpentry.date(), FtpEntryDate::from_ymd(2012, 9, 2));
 
         assert_eq!(ftpentry.is_msdos_type(), false);
         assert_eq!(ftpentry.is_unix_type(), true);
@@ -379,8 +372,9 @@ mod unix {
         assert_eq!(ftpentry_unix.sticky, false);
         assert_eq!(ftpentry_unix.permissions.as_str(), "rw-rw-rw-");
         assert_eq!(ftpentry_unix.acl, true);
-        assert_eq!(ftpentry_unix.owner, "owner");
-        assert_eq!(ftpentry_unix.group, "group");
+        assert_eq!(ftpentry_unix.xattr, false);
+        assert_eq!(ftpentry_unix.owner.as_deref(), Some("owner"));
+        assert_eq!(ftpentry_unix.group.as_deref(), Some("group"));
         assert_eq!(ftpentry_unix.pointer, None);
     }
 
@@ -396,10 +390,7 @@ mod unix {
         assert_eq!(ftpentry.name(), "tmp");
         assert_eq!(ftpentry.size(), 4096);
         assert_eq!(ftpentry.date_str(), "May 19 2012");
-        // assert_eq!(
-        //     ftpentry.date(),
-        //     NaiveDate::from_ymd(2012, 5, 19).and_hms(0, 0, 0)
-        // );
+        assert_eq!(ftpentry.date(), FtpEntryDate::from_ymd(2012, 5, 19));
 
         assert_eq!(ftpentry.is_msdos_type(), false);
         assert_eq!(ftpentry.is_unix_type(), true);
@@ -410,8 +401,8 @@ mod unix {
         assert_eq!(ftpentry_unix.sticky, true);
         assert_eq!(ftpentry_unix.permissions.as_str(), "rwxrwxrwx");
         assert_eq!(ftpentry_unix.acl, false);
-        assert_eq!(ftpentry_unix.owner, "root");
-        assert_eq!(ftpentry_unix.group, "root");
+        assert_eq!(ftpentry_unix.owner.as_deref(), Some("root"));
+        assert_eq!(ftpentry_unix.group.as_deref(), Some("root"));
         assert_eq!(ftpentry_unix.pointer, None);
     }
 
@@ -427,10 +418,7 @@ mod unix {
         assert_eq!(ftpentry.name(), "tmp");
         assert_eq!(ftpentry.size(), 4096);
         assert_eq!(ftpentry.date_str(), "May 19 2012");
-        // assert_eq!(
-        //     ftpentry.date(),
-        //     NaiveDate::from_ymd(2012, 5, 19).and_hms(0, 0, 0)
-        // );
+        assert_eq!(ftpentry.date(), FtpEntryDate::from_ymd(2012, 5, 19));
 
         assert_eq!(ftpentry.is_msdos_type(), false);
         assert_eq!(ftpentry.is_unix_type(), true);
@@ -441,8 +429,8 @@ mod unix {
         assert_eq!(ftpentry_unix.sticky, true);
         assert_eq!(ftpentry_unix.permissions.as_str(), "rwxrwx--x");
         assert_eq!(ftpentry_unix.acl, false);
-        assert_eq!(ftpentry_unix.owner, "root");
-        assert_eq!(ftpentry_unix.group, "root");
+        assert_eq!(ftpentry_unix.owner.as_deref(), Some("root"));
+        assert_eq!(ftpentry_unix.group.as_deref(), Some("root"));
         assert_eq!(ftpentry_unix.pointer, None);
     }
 
@@ -458,10 +446,7 @@ mod unix {
         assert_eq!(ftpentry.name(), "tmp");
         assert_eq!(ftpentry.size(), 4096);
         assert_eq!(ftpentry.date_str(), "May 19 2012");
-        // assert_eq!(
-        //     ftpentry.date(),
-        //     NaiveDate::from_ymd(2012, 5, 19).and_hms(0, 0, 0)
-        // );
+        assert_eq!(ftpentry.date(), FtpEntryDate::from_ymd(2012, 5, 19));
 
         assert_eq!(ftpentry.is_msdos_type(), false);
         assert_eq!(ftpentry.is_unix_type(), true);
@@ -472,8 +457,8 @@ mod unix {
         assert_eq!(ftpentry_unix.sticky, true);
         assert_eq!(ftpentry_unix.permissions.as_str(), "rwxrwxrw-");
         assert_eq!(ftpentry_unix.acl, false);
-        assert_eq!(ftpentry_unix.owner, "root");
-        assert_eq!(ftpentry_unix.group, "root");
+        assert_eq!(ftpentry_unix.owner.as_deref(), Some("root"));
+        assert_eq!(ftpentry_unix.group.as_deref(), Some("root"));
         assert_eq!(ftpentry_unix.pointer, None);
     }
 
@@ -489,10 +474,7 @@ mod unix {
         assert_eq!(ftpentry.name(), "tmp");
         assert_eq!(ftpentry.size(), 4096);
         assert_eq!(ftpentry.date_str(), "May 19 2012");
-        // assert_eq!(
-        //     ftpentry.date(),
-        //     NaiveDate::from_ymd(2012, 5, 19).and_hms(0, 0, 0)
-        // );
+        assert_eq!(ftpentry.date(), FtpEntryDate::from_ymd(2012, 5, 19));
 
         assert_eq!(ftpentry.is_msdos_type(), false);
         assert_eq!(ftpentry.is_unix_type(), true);
@@ -503,8 +485,8 @@ mod unix {
         assert_eq!(ftpentry_unix.sticky, true);
         assert_eq!(ftpentry_unix.permissions.as_str(), "rwxrwx---");
         assert_eq!(ftpentry_unix.acl, false);
-        assert_eq!(ftpentry_unix.owner, "root");
-        assert_eq!(ftpentry_unix.group, "root");
+        assert_eq!(ftpentry_unix.owner.as_deref(), Some("root"));
+        assert_eq!(ftpentry_unix.group.as_deref(), Some("root"));
         assert_eq!(ftpentry_unix.pointer, None);
     }
 
@@ -520,11 +502,11 @@ mod unix {
         assert_eq!(ftpentry.name(), "wp-content");
         assert_eq!(ftpentry.size(), 208);
         assert_eq!(ftpentry.date_str(), "May 5 11:28");
-        //TODO: checks for correct year
-        // assert_eq!(
-        //     ftpentry.date(),
-        //     NaiveDate::from_ymd(ftpentry.date().year(), 5, 5).and_hms(11, 28, 0)
-        // );
+        assert_eq!(ftpentry.date(), FtpEntryDate::from_md_hm(5, 5, 11, 28));
+        assert_eq!(
+            ftpentry.date().unwrap().infer_year(now()).year(),
+            Some(2020)
+        );
 
         assert_eq!(ftpentry.is_msdos_type(), false);
         assert_eq!(ftpentry.is_unix_type(), true);
@@ -535,8 +517,8 @@ mod unix {
         assert_eq!(ftpentry_unix.sticky, false);
         assert_eq!(ftpentry_unix.permissions.as_str(), "rwxrwxr-x");
         assert_eq!(ftpentry_unix.acl, false);
-        assert_eq!(ftpentry_unix.owner, "1317");
-        assert_eq!(ftpentry_unix.group, "Domain Use");
+        assert_eq!(ftpentry_unix.owner.as_deref(), Some("1317"));
+        assert_eq!(ftpentry_unix.group.as_deref(), Some("Domain Use"));
         assert_eq!(ftpentry_unix.pointer, None);
     }
 
@@ -552,11 +534,11 @@ mod unix {
         assert_eq!(ftpentry.name(), "wp-content");
         assert_eq!(ftpentry.size(), 208);
         assert_eq!(ftpentry.date_str(), "May 5 11:28");
-        //TODO: checks for correct year
-        // assert_eq!(
-        //     ftpentry.date(),
-        //     NaiveDate::from_ymd(ftpentry.date().year(), 5, 5).and_hms(11, 28, 0)
-        // );
+        assert_eq!(ftpentry.date(), FtpEntryDate::from_md_hm(5, 5, 11, 28));
+        assert_eq!(
+            ftpentry.date().unwrap().infer_year(now()).year(),
+            Some(2020)
+        );
 
         assert_eq!(ftpentry.is_msdos_type(), false);
         assert_eq!(ftpentry.is_unix_type(), true);
@@ -567,8 +549,8 @@ mod unix {
         assert_eq!(ftpentry_unix.sticky, false);
         assert_eq!(ftpentry_unix.permissions.as_str(), "rwxrwxr-x");
         assert_eq!(ftpentry_unix.acl, false);
-        assert_eq!(ftpentry_unix.owner, "1317");
-        assert_eq!(ftpentry_unix.group, "Domain  Use");
+        assert_eq!(ftpentry_unix.owner.as_deref(), Some("1317"));
+        assert_eq!(ftpentry_unix.group.as_deref(), Some("Domain  Use"));
         assert_eq!(ftpentry_unix.pointer, None);
     }
 
@@ -584,11 +566,11 @@ mod unix {
         assert_eq!(ftpentry.name(), "wp-content");
         assert_eq!(ftpentry.size(), 208);
         assert_eq!(ftpentry.date_str(), "May 5 11:28");
-        //TODO: checks for correct year
-        // assert_eq!(
-        //     ftpentry.date(),
-        //     NaiveDate::from_ymd(ftpentry.date().year(), 5, 5).and_hms(11, 28, 0)
-        // );
+        assert_eq!(ftpentry.date(), FtpEntryDate::from_md_hm(5, 5, 11, 28));
+        assert_eq!(
+            ftpentry.date().unwrap().infer_year(now()).year(),
+            Some(2020)
+        );
 
         assert_eq!(ftpentry.is_msdos_type(), false);
         assert_eq!(ftpentry.is_unix_type(), true);
@@ -599,8 +581,8 @@ mod unix {
         assert_eq!(ftpentry_unix.sticky, false);
         assert_eq!(ftpentry_unix.permissions.as_str(), "rwxrwxr-x");
         assert_eq!(ftpentry_unix.acl, false);
-        assert_eq!(ftpentry_unix.owner, "Domain Use");
-        assert_eq!(ftpentry_unix.group, "Domain Use");
+        assert_eq!(ftpentry_unix.owner.as_deref(), Some("Domain Use"));
+        assert_eq!(ftpentry_unix.group.as_deref(), Some("Domain Use"));
         assert_eq!(ftpentry_unix.pointer, None);
     }
 
@@ -616,10 +598,7 @@ mod unix {
         assert_eq!(ftpentry.name(), ".pki");
         assert_eq!(ftpentry.size(), 18);
         assert_eq!(ftpentry.date_str(), "Jul 04 2017");
-        // assert_eq!(
-        //     ftpentry.date(),
-        //     NaiveDate::from_ymd(2017, 7, 4).and_hms(0, 0, 0)
-        // );
+        assert_eq!(ftpentry.date(), FtpEntryDate::from_ymd(2017, 7, 4));
 
         assert_eq!(ftpentry.is_msdos_type(), false);
         assert_eq!(ftpentry.is_unix_type(), true);
@@ -629,9 +608,12 @@ mod unix {
         assert_eq!(ftpentry_unix.target, None);
         assert_eq!(ftpentry_unix.sticky, false);
         assert_eq!(ftpentry_unix.permissions.as_str(), "rwxr-S---");
+        assert_eq!(ftpentry_unix.setuid, false);
+        assert_eq!(ftpentry_unix.setgid, true);
+        assert_eq!(ftpentry_unix.permissions.group().execute, false);
         assert_eq!(ftpentry_unix.acl, false);
-        assert_eq!(ftpentry_unix.owner, "105207");
-        assert_eq!(ftpentry_unix.group, "501");
+        assert_eq!(ftpentry_unix.owner.as_deref(), Some("105207"));
+        assert_eq!(ftpentry_unix.group.as_deref(), Some("501"));
         assert_eq!(ftpentry_unix.pointer, None);
     }
 
@@ -648,10 +630,7 @@ mod unix {
         assert_eq!(ftpentry.name(), ".pki");
         assert_eq!(ftpentry.size(), 18);
         assert_eq!(ftpentry.date_str(), "Jul 04 2017");
-        // assert_eq!(
-        //     ftpentry.date(),
-        //     NaiveDate::from_ymd(2017, 7, 4).and_hms(0, 0, 0)
-        // );
+        assert_eq!(ftpentry.date(), FtpEntryDate::from_ymd(2017, 7, 4));
 
         assert_eq!(ftpentry.is_msdos_type(), false);
         assert_eq!(ftpentry.is_unix_type(), true);
@@ -661,12 +640,27 @@ mod unix {
         assert_eq!(ftpentry_unix.target, None);
         assert_eq!(ftpentry_unix.sticky, false);
         assert_eq!(ftpentry_unix.permissions.as_str(), "rwxr-s---");
+        assert_eq!(ftpentry_unix.setuid, false);
+        assert_eq!(ftpentry_unix.setgid, true);
+        assert_eq!(ftpentry_unix.permissions.group().execute, true);
         assert_eq!(ftpentry_unix.acl, false);
-        assert_eq!(ftpentry_unix.owner, "105207");
-        assert_eq!(ftpentry_unix.group, "501");
+        assert_eq!(ftpentry_unix.owner.as_deref(), Some("105207"));
+        assert_eq!(ftpentry_unix.group.as_deref(), Some("501"));
         assert_eq!(ftpentry_unix.pointer, None);
     }
 
+    #[test]
+    fn file_with_setuid_bit() {
+        let row = "-rwsr-xr-x    1 root     root        54256 Mar 14  2019 passwd";
+        let ftpentry = FtpEntryUnix::try_from(row).unwrap();
+
+        assert_eq!(ftpentry.setuid, true);
+        assert_eq!(ftpentry.setgid, false);
+        assert_eq!(ftpentry.mandatory_locking, false);
+        assert_eq!(ftpentry.permissions.user().execute, true);
+        assert_eq!(ftpentry.permissions.mode(), 0o4755);
+    }
+
     #[test]
     fn directory_with_mandatory_lock() {
         let row = "drwx--L---    3 105207   501            18 Jul 04  2017 .pki";
@@ -679,10 +673,7 @@ mod unix {
         assert_eq!(ftpentry.name(), ".pki");
         assert_eq!(ftpentry.size(), 18);
         assert_eq!(ftpentry.date_str(), "Jul 04 2017");
-        // assert_eq!(
-        //     ftpentry.date(),
-        //     NaiveDate::from_ymd(2017, 7, 4).and_hms(0, 0, 0)
-        // );
+        assert_eq!(ftpentry.date(), FtpEntryDate::from_ymd(2017, 7, 4));
 
         assert_eq!(ftpentry.is_msdos_type(), false);
         assert_eq!(ftpentry.is_unix_type(), true);
@@ -692,9 +683,14 @@ mod unix {
         assert_eq!(ftpentry_unix.target, None);
         assert_eq!(ftpentry_unix.sticky, false);
         assert_eq!(ftpentry_unix.permissions.as_str(), "rwx--L---");
+        assert_eq!(ftpentry_unix.setgid, true);
+        assert_eq!(ftpentry_unix.mandatory_locking, true);
+        assert_eq!(ftpentry_unix.permissions.group().execute, false);
+        assert_eq!(ftpentry_unix.permissions.mode(), 0o2700);
+        assert_eq!(ftpentry_unix.permissions.to_string(), "rwx--L---");
         assert_eq!(ftpentry_unix.acl, false);
-        assert_eq!(ftpentry_unix.owner, "105207");
-        assert_eq!(ftpentry_unix.group, "501");
+        assert_eq!(ftpentry_unix.owner.as_deref(), Some("105207"));
+        assert_eq!(ftpentry_unix.group.as_deref(), Some("501"));
         assert_eq!(ftpentry_unix.pointer, None);
     }
 
@@ -710,10 +706,7 @@ mod unix {
         assert_eq!(ftpentry.name(), ".pki");
         assert_eq!(ftpentry.size(), 18);
         assert_eq!(ftpentry.date_str(), "Jul 04 2017");
-        // assert_eq!(
-        //     ftpentry.date(),
-        //     NaiveDate::from_ymd(2017, 7, 4).and_hms(0, 0, 0)
-        // );
+        assert_eq!(ftpentry.date(), FtpEntryDate::from_ymd(2017, 7, 4));
 
         assert_eq!(ftpentry.is_msdos_type(), false);
         assert_eq!(ftpentry.is_unix_type(), true);
@@ -724,8 +717,8 @@ mod unix {
         assert_eq!(ftpentry_unix.sticky, false);
         assert_eq!(ftpentry_unix.permissions.as_str(), "rwx-w----");
         assert_eq!(ftpentry_unix.acl, false);
-        assert_eq!(ftpentry_unix.owner, "105207");
-        assert_eq!(ftpentry_unix.group, "501");
+        assert_eq!(ftpentry_unix.owner.as_deref(), Some("105207"));
+        assert_eq!(ftpentry_unix.group.as_deref(), Some("501"));
         assert_eq!(ftpentry_unix.pointer, None);
     }
 
@@ -741,11 +734,11 @@ mod unix {
         assert_eq!(ftpentry.name(), "sda");
         assert_eq!(ftpentry.size(), 0);
         assert_eq!(ftpentry.date_str(), "Nov 24 10:13");
-        //TODO: checks for correct year
-        // assert_eq!(
-        //     ftpentry.date(),
-        //     NaiveDate::from_ymd(ftpentry.date().year(), 11, 24).and_hms(10, 13, 0)
-        // );
+        assert_eq!(ftpentry.date(), FtpEntryDate::from_md_hm(11, 24, 10, 13));
+        assert_eq!(
+            ftpentry.date().unwrap().infer_year(now()).year(),
+            Some(2019)
+        );
 
         assert_eq!(ftpentry.is_msdos_type(), false);
         assert_eq!(ftpentry.is_unix_type(), true);
@@ -756,9 +749,16 @@ mod unix {
         assert_eq!(ftpentry_unix.sticky, false);
         assert_eq!(ftpentry_unix.permissions.as_str(), "rw-rw----");
         assert_eq!(ftpentry_unix.acl, false);
-        assert_eq!(ftpentry_unix.owner, "root");
-        assert_eq!(ftpentry_unix.group, "disk");
+        assert_eq!(ftpentry_unix.owner.as_deref(), Some("root"));
+        assert_eq!(ftpentry_unix.group.as_deref(), Some("disk"));
         assert_eq!(ftpentry_unix.pointer, Some("8,0".to_string()));
+        assert_eq!(
+            ftpentry_unix.device,
+            Some(FtpEntryUnixDevice {
+                major: 8,
+                minor: Some(0)
+            })
+        );
     }
 
     #[test]
@@ -773,11 +773,11 @@ mod unix {
         assert_eq!(ftpentry.name(), "vcs");
         assert_eq!(ftpentry.size(), 0);
         assert_eq!(ftpentry.date_str(), "Apr 1 20:30");
-        //TODO: checks for correct year
-        // assert_eq!(
-        //     ftpentry.date(),
-        //     NaiveDate::from_ymd(ftpentry.date().year(), 4, 1).and_hms(20, 30, 0)
-        // );
+        assert_eq!(ftpentry.date(), FtpEntryDate::from_md_hm(4, 1, 20, 30));
+        assert_eq!(
+            ftpentry.date().unwrap().infer_year(now()).year(),
+            Some(2020)
+        );
 
         assert_eq!(ftpentry.is_msdos_type(), false);
         assert_eq!(ftpentry.is_unix_type(), true);
@@ -788,9 +788,10 @@ mod unix {
         assert_eq!(ftpentry_unix.sticky, false);
         assert_eq!(ftpentry_unix.permissions.as_str(), "rw-rw----");
         assert_eq!(ftpentry_unix.acl, false);
-        assert_eq!(ftpentry_unix.owner, "root");
-        assert_eq!(ftpentry_unix.group, "tty");
+        assert_eq!(ftpentry_unix.owner.as_deref(), Some("root"));
+        assert_eq!(ftpentry_unix.group.as_deref(), Some("tty"));
         assert_eq!(ftpentry_unix.pointer, None);
+        assert_eq!(ftpentry_unix.device, None);
     }
 
     #[test]
@@ -805,11 +806,11 @@ mod unix {
         assert_eq!(ftpentry.name(), "vcsa6");
         assert_eq!(ftpentry.size(), 0);
         assert_eq!(ftpentry.date_str(), "Apr 1 20:30");
-        //TODO: checks for correct year
-        // assert_eq!(
-        //     ftpentry.date(),
-        //     NaiveDate::from_ymd(ftpentry.date().year(), 4, 1).and_hms(20, 30, 0)
-        // );
+        assert_eq!(ftpentry.date(), FtpEntryDate::from_md_hm(4, 1, 20, 30));
+        assert_eq!(
+            ftpentry.date().unwrap().infer_year(now()).year(),
+            Some(2020)
+        );
 
         assert_eq!(ftpentry.is_msdos_type(), false);
         assert_eq!(ftpentry.is_unix_type(), true);
@@ -820,9 +821,16 @@ mod unix {
         assert_eq!(ftpentry_unix.sticky, false);
         assert_eq!(ftpentry_unix.permissions.as_str(), "rw-rw----");
         assert_eq!(ftpentry_unix.acl, false);
-        assert_eq!(ftpentry_unix.owner, "root");
-        assert_eq!(ftpentry_unix.group, "tty");
+        assert_eq!(ftpentry_unix.owner.as_deref(), Some("root"));
+        assert_eq!(ftpentry_unix.group.as_deref(), Some("tty"));
         assert_eq!(ftpentry_unix.pointer, Some("7,134".to_string()));
+        assert_eq!(
+            ftpentry_unix.device,
+            Some(FtpEntryUnixDevice {
+                major: 7,
+                minor: Some(134)
+            })
+        );
     }
 
     #[test]
@@ -837,11 +845,11 @@ mod unix {
         assert_eq!(ftpentry.name(), "vcs");
         assert_eq!(ftpentry.size(), 0);
         assert_eq!(ftpentry.date_str(), "Apr 1 20:30");
-        //TODO: checks for correct year
-        // assert_eq!(
-        //     ftpentry.date(),
-        //     NaiveDate::from_ymd(ftpentry.date().year(), 4, 1).and_hms(20, 30, 0)
-        // );
+        assert_eq!(ftpentry.date(), FtpEntryDate::from_md_hm(4, 1, 20, 30));
+        assert_eq!(
+            ftpentry.date().unwrap().infer_year(now()).year(),
+            Some(2020)
+        );
 
         assert_eq!(ftpentry.is_msdos_type(), false);
         assert_eq!(ftpentry.is_unix_type(), true);
@@ -852,9 +860,65 @@ mod unix {
         assert_eq!(ftpentry_unix.sticky, false);
         assert_eq!(ftpentry_unix.permissions.as_str(), "rw-rw----");
         assert_eq!(ftpentry_unix.acl, false);
-        assert_eq!(ftpentry_unix.owner, "root");
-        assert_eq!(ftpentry_unix.group, "tty");
+        assert_eq!(ftpentry_unix.owner.as_deref(), Some("root"));
+        assert_eq!(ftpentry_unix.group.as_deref(), Some("tty"));
         assert_eq!(ftpentry_unix.pointer, Some("7,0".to_string()));
+        assert_eq!(
+            ftpentry_unix.device,
+            Some(FtpEntryUnixDevice {
+                major: 7,
+                minor: Some(0)
+            })
+        );
+    }
+
+    #[test]
+    fn block_device_without_minor() {
+        let row = "brw-rw---- 1 root disk 8, Nov 24 10:13 sda";
+
+        let ftpentry = FtpEntryUnix::try_from(row).unwrap();
+
+        assert_eq!(ftpentry.kind(), FtpEntryKind::BlockDevice);
+        assert_eq!(ftpentry.name(), "sda");
+        assert_eq!(ftpentry.size(), 0);
+        assert_eq!(ftpentry.date_str(), "Nov 24 10:13");
+        assert_eq!(ftpentry.pointer, Some("8,".to_string()));
+        assert_eq!(
+            ftpentry.device,
+            Some(FtpEntryUnixDevice {
+                major: 8,
+                minor: None
+            })
+        );
+    }
+
+    #[test]
+    fn error_device_number_of_file() {
+        let row = "-rw-r--r-- 1 owner group 1,234 Sep 02 2012 file";
+
+        let err = FtpEntry::try_from(row).unwrap_err();
+        assert_eq!(err.format(), Some(FtpEntryFormat::Unix));
+        assert_eq!(err.field(), FtpEntryField::Size);
+        assert_eq!(err.offset(), 25);
+    }
+
+    #[test]
+    fn character_device_with_hex_minor() {
+        let row = "crw-r-----  1 root  operator    4, 0x00030000 Apr  1 20:30 ad0s1";
+
+        let ftpentry = FtpEntryUnix::try_from(row).unwrap();
+
+        assert_eq!(ftpentry.kind(), FtpEntryKind::CharacterDevice);
+        assert_eq!(ftpentry.name(), "ad0s1");
+        assert_eq!(ftpentry.size(), 0);
+        assert_eq!(ftpentry.pointer, Some("4,0x00030000".to_string()));
+        assert_eq!(
+            ftpentry.device,
+            Some(FtpEntryUnixDevice {
+                major: 4,
+                minor: Some(0x30000)
+            })
+        );
     }
 
     #[test]
@@ -869,10 +933,7 @@ mod unix {
         assert_eq!(ftpentry.name(), ".pki");
         assert_eq!(ftpentry.size(), 18);
         assert_eq!(ftpentry.date_str(), "Jul 04 2017");
-        // assert_eq!(
-        //     ftpentry.date(),
-        //     NaiveDate::from_ymd(2017, 7, 4).and_hms(0, 0, 0)
-        // );
+        assert_eq!(ftpentry.date(), FtpEntryDate::from_ymd(2017, 7, 4));
 
         assert_eq!(ftpentry.is_msdos_type(), false);
         assert_eq!(ftpentry.is_unix_type(), true);
@@ -883,8 +944,8 @@ mod unix {
         assert_eq!(ftpentry_unix.sticky, false);
         assert_eq!(ftpentry_unix.permissions.as_str(), "rwx-w----");
         assert_eq!(ftpentry_unix.acl, false);
-        assert_eq!(ftpentry_unix.owner, "105207");
-        assert_eq!(ftpentry_unix.group, "501");
+        assert_eq!(ftpentry_unix.owner.as_deref(), Some("105207"));
+        assert_eq!(ftpentry_unix.group.as_deref(), Some("501"));
         assert_eq!(ftpentry_unix.pointer, None);
     }
 
@@ -900,10 +961,7 @@ mod unix {
         assert_eq!(ftpentry.name(), ".pki");
         assert_eq!(ftpentry.size(), 18);
         assert_eq!(ftpentry.date_str(), "Jul 04 2017");
-        // assert_eq!(
-        //     ftpentry.date(),
-        //     NaiveDate::from_ymd(2017, 7, 4).and_hms(0, 0, 0)
-        // );
+        assert_eq!(ftpentry.date(), FtpEntryDate::from_ymd(2017, 7, 4));
 
         assert_eq!(ftpentry.is_msdos_type(), false);
         assert_eq!(ftpentry.is_unix_type(), true);
@@ -914,8 +972,8 @@ mod unix {
         assert_eq!(ftpentry_unix.sticky, false);
         assert_eq!(ftpentry_unix.permissions.as_str(), "rwx-w----");
         assert_eq!(ftpentry_unix.acl, false);
-        assert_eq!(ftpentry_unix.owner, "105207");
-        assert_eq!(ftpentry_unix.group, "501");
+        assert_eq!(ftpentry_unix.owner.as_deref(), Some("105207"));
+        assert_eq!(ftpentry_unix.group.as_deref(), Some("501"));
         assert_eq!(ftpentry_unix.pointer, None);
     }
 
@@ -931,10 +989,11 @@ mod unix {
         assert_eq!(ftpentry.name(), "www.nodeftp.github");
         assert_eq!(ftpentry.size(), 51);
         assert_eq!(ftpentry.date_str(), "Apr 4 23:57");
-        // assert_eq!(
-        //     ftpentry.date(),
-        //     NaiveDate::from_ymd(ftpentry.date().year(), 4, 4).and_hms(23, 57, 0)
-        // );
+        assert_eq!(ftpentry.date(), FtpEntryDate::from_md_hm(4, 4, 23, 57));
+        assert_eq!(
+            ftpentry.date().unwrap().infer_year(now()).year(),
+            Some(2020)
+        );
 
         assert_eq!(ftpentry.is_msdos_type(), false);
         assert_eq!(ftpentry.is_unix_type(), true);
@@ -948,8 +1007,8 @@ mod unix {
         assert_eq!(ftpentry_unix.sticky, false);
         assert_eq!(ftpentry_unix.permissions.as_str(), "rwxrwxrwx");
         assert_eq!(ftpentry_unix.acl, false);
-        assert_eq!(ftpentry_unix.owner, "root");
-        assert_eq!(ftpentry_unix.group, "root");
+        assert_eq!(ftpentry_unix.owner.as_deref(), Some("root"));
+        assert_eq!(ftpentry_unix.group.as_deref(), Some("root"));
         assert_eq!(ftpentry_unix.pointer, None);
     }
 
@@ -965,11 +1024,7 @@ mod unix {
         assert_eq!(ftpentry.name(), "music.mp3");
         assert_eq!(ftpentry.size(), 7045120);
         assert_eq!(ftpentry.date_str(), "Sep 02 2012");
-        //TODO: checks for correct year
-        // assert_eq!(
-        //     ftpentry.date(),
-        //     NaiveDate::from_ymd(2012, 9, 2).and_hms(0, 0, 0)
-        // );
+        assert_eq!(ftpentry.date(), FtpEntryDate::from_ymd(2012, 9, 2));
 
         assert_eq!(ftpentry.is_msdos_type(), false);
         assert_eq!(ftpentry.is_unix_type(), true);
@@ -980,11 +1035,25 @@ mod unix {
         assert_eq!(ftpentry_unix.sticky, false);
         assert_eq!(ftpentry_unix.permissions.as_str(), "rw-rw-rw-");
         assert_eq!(ftpentry_unix.acl, false);
-        assert_eq!(ftpentry_unix.owner, "owner");
-        assert_eq!(ftpentry_unix.group, "group");
+        assert_eq!(ftpentry_unix.xattr, true);
+        assert_eq!(ftpentry_unix.selinux, false);
+        assert_eq!(ftpentry_unix.owner.as_deref(), Some("owner"));
+        assert_eq!(ftpentry_unix.group.as_deref(), Some("group"));
         assert_eq!(ftpentry_unix.pointer, None);
     }
 
+    #[test]
+    fn file_with_selinux_context() {
+        let row = "-rw-r--r--.  1 root root 1024 Sep 02  2012 hosts";
+        let ftpentry = FtpEntryUnix::try_from(row).unwrap();
+
+        assert_eq!(ftpentry.name(), "hosts");
+        assert_eq!(ftpentry.permissions.as_str(), "rw-r--r--");
+        assert_eq!(ftpentry.acl, false);
+        assert_eq!(ftpentry.xattr, false);
+        assert_eq!(ftpentry.selinux, true);
+    }
+
     #[test]
     fn directory_with_special_name_2() {
         let row = "drwxr-xr-x  10 root   root    4096 Dec 21  2012 1.1 Header [13]";
@@ -997,10 +1066,7 @@ mod unix {
         assert_eq!(ftpentry.name(), "1.1 Header [13]");
         assert_eq!(ftpentry.size(), 4096);
         assert_eq!(ftpentry.date_str(), "Dec 21 2012");
-        // assert_eq!(
-        //     ftpentry.date(),
-        //     NaiveDate::from_ymd(2012, 12, 21).and_hms(0, 0, 0)
-        // );
+        assert_eq!(ftpentry.date(), FtpEntryDate::from_ymd(2012, 12, 21));
 
         assert_eq!(ftpentry.is_msdos_type(), false);
         assert_eq!(ftpentry.is_unix_type(), true);
@@ -1011,8 +1077,8 @@ mod unix {
         assert_eq!(ftpentry_unix.sticky, false);
         assert_eq!(ftpentry_unix.permissions.as_str(), "rwxr-xr-x");
         assert_eq!(ftpentry_unix.acl, false);
-        assert_eq!(ftpentry_unix.owner, "root");
-        assert_eq!(ftpentry_unix.group, "root");
+        assert_eq!(ftpentry_unix.owner.as_deref(), Some("root"));
+        assert_eq!(ftpentry_unix.group.as_deref(), Some("root"));
         assert_eq!(ftpentry_unix.pointer, None);
     }
 
@@ -1028,11 +1094,11 @@ mod unix {
         assert_eq!(ftpentry.name(), "wwwroot");
         assert_eq!(ftpentry.size(), 0);
         assert_eq!(ftpentry.date_str(), "Aug 22 14:05");
-        //TODO: checks for correct year
-        // assert_eq!(
-        //     ftpentry.date(),
-        //     NaiveDate::from_ymd(ftpentry.date().year(), 8, 22).and_hms(14, 5, 0)
-        // );
+        assert_eq!(ftpentry.date(), FtpEntryDate::from_md_hm(8, 22, 14, 5));
+        assert_eq!(
+            ftpentry.date().unwrap().infer_year(now()).year(),
+            Some(2019)
+        );
 
         assert_eq!(ftpentry.is_msdos_type(), false);
         assert_eq!(ftpentry.is_unix_type(), true);
@@ -1043,8 +1109,8 @@ mod unix {
         assert_eq!(ftpentry_unix.sticky, false);
         assert_eq!(ftpentry_unix.permissions.as_str(), "rwxrwxrwx");
         assert_eq!(ftpentry_unix.acl, false);
-        assert_eq!(ftpentry_unix.owner, "owner");
-        assert_eq!(ftpentry_unix.group, "group");
+        assert_eq!(ftpentry_unix.owner.as_deref(), Some("owner"));
+        assert_eq!(ftpentry_unix.group.as_deref(), Some("group"));
         assert_eq!(ftpentry_unix.pointer, None);
     }
 
@@ -1061,10 +1127,11 @@ mod unix {
         assert_eq!(ftpentry.size(), 0);
         assert_eq!(ftpentry.date_str(), "Aug 22 14:05");
         // //TODO: checks for correct year
-        // assert_eq!(
-        //     ftpentry.date(),
-        //     NaiveDate::from_ymd(ftpentry.date().year(), 8, 22).and_hms(14, 5, 0)
-        // );
+        assert_eq!(ftpentry.date(), FtpEntryDate::from_md_hm(8, 22, 14, 5));
+        assert_eq!(
+            ftpentry.date().unwrap().infer_year(now()).year(),
+            Some(2019)
+        );
 
         assert_eq!(ftpentry.is_msdos_type(), false);
         assert_eq!(ftpentry.is_unix_type(), true);
@@ -1075,8 +1142,8 @@ mod unix {
         assert_eq!(ftpentry_unix.sticky, false);
         assert_eq!(ftpentry_unix.permissions.as_str(), "rwxrwxrwx");
         assert_eq!(ftpentry_unix.acl, false);
-        assert_eq!(ftpentry_unix.owner, "owner");
-        assert_eq!(ftpentry_unix.group, "group");
+        assert_eq!(ftpentry_unix.owner.as_deref(), Some("owner"));
+        assert_eq!(ftpentry_unix.group.as_deref(), Some("group"));
         assert_eq!(ftpentry_unix.pointer, None);
     }
 
@@ -1094,10 +1161,11 @@ mod unix {
         assert_eq!(ftpentry.date_str(), "Aug 22 12:59");
 
         // //TODO: checks for correct year
-        // assert_eq!(
-        //     ftpentry.date(),
-        //     NaiveDate::from_ymd(ftpentry.date().year(), 8, 22).and_hms(12, 59, 0)
-        // );
+        assert_eq!(ftpentry.date(), FtpEntryDate::from_md_hm(8, 22, 12, 59));
+        assert_eq!(
+            ftpentry.date().unwrap().infer_year(now()).year(),
+            Some(2019)
+        );
 
         assert_eq!(ftpentry.is_msdos_type(), false);
         assert_eq!(ftpentry.is_unix_type(), true);
@@ -1108,8 +1176,8 @@ mod unix {
         assert_eq!(ftpentry_unix.sticky, false);
         assert_eq!(ftpentry_unix.permissions.as_str(), "rwxrwxrwx");
         assert_eq!(ftpentry_unix.acl, false);
-        assert_eq!(ftpentry_unix.owner, "owner");
-        assert_eq!(ftpentry_unix.group, "group");
+        assert_eq!(ftpentry_unix.owner.as_deref(), Some("owner"));
+        assert_eq!(ftpentry_unix.group.as_deref(), Some("group"));
         assert_eq!(ftpentry_unix.pointer, None);
     }
 
@@ -1126,10 +1194,11 @@ mod unix {
         assert_eq!(ftpentry.size(), 6148);
         assert_eq!(ftpentry.date_str(), "Sep 19 06:17");
         // //TODO: checks for correct year
-        // assert_eq!(
-        //     ftpentry.date(),
-        //     NaiveDate::from_ymd(ftpentry.date().year(), 9, 19).and_hms(6, 17, 0)
-        // );
+        assert_eq!(ftpentry.date(), FtpEntryDate::from_md_hm(9, 19, 6, 17));
+        assert_eq!(
+            ftpentry.date().unwrap().infer_year(now()).year(),
+            Some(2019)
+        );
 
         assert_eq!(ftpentry.is_msdos_type(), false);
         assert_eq!(ftpentry.is_unix_type(), true);
@@ -1140,8 +1209,244 @@ mod unix {
         assert_eq!(ftpentry_unix.sticky, false);
         assert_eq!(ftpentry_unix.permissions.as_str(), "rw-r--r--");
         assert_eq!(ftpentry_unix.acl, false);
-        assert_eq!(ftpentry_unix.owner, "300794");
-        assert_eq!(ftpentry_unix.group, r"AD\\Domain Users");
+        assert_eq!(ftpentry_unix.owner.as_deref(), Some("300794"));
+        assert_eq!(ftpentry_unix.group.as_deref(), Some(r"AD\\Domain Users"));
         assert_eq!(ftpentry_unix.pointer, None);
     }
+
+    #[test]
+    fn borrowed_symlink() {
+        let row = "lrwxrwxrwx   1 owner   group    7 Sep 02 10:13 link -> target";
+        let entry = FtpEntryRef::try_from(row).unwrap();
+
+        assert_eq!(entry.name(), "link");
+        assert_eq!(entry.date_str(), "Sep 02 10:13");
+
+        let unix = match &entry {
+            FtpEntryRef::Unix(unix) => unix,
+            _ => panic!("not a unix entry"),
+        };
+        assert_eq!(unix.target, Some("target"));
+        assert_eq!(unix.owner, Some("owner"));
+        assert!(std::ptr::eq(unix.owner.unwrap(), &row[15..20]));
+
+        let owned = entry.to_owned().to_unix_type();
+        assert_eq!(owned.name(), "link");
+        assert_eq!(owned.target.as_deref(), Some("target"));
+        assert_eq!(owned.owner.as_deref(), Some("owner"));
+    }
+
+    #[test]
+    fn borrowed_date_with_double_space() {
+        let row = "-rw-rw-rw-   1 owner   group    7045120 Sep 02  2012 music.mp3";
+        let entry = FtpEntryUnixRef::try_from(row).unwrap();
+
+        assert_eq!(entry.date_str(), "Sep 02 2012");
+        assert_eq!(entry.to_owned().date_str(), "Sep 02 2012");
+    }
+
+    #[test]
+    fn single_ownership_column() {
+        let row = "-rw-r--r--    1 ftp          1024 Sep 02  2012 busybox.txt";
+        let ftpentry = FtpEntryUnix::try_from(row).unwrap();
+
+        assert_eq!(ftpentry.ownership.as_deref(), Some("ftp"));
+        assert_eq!(ftpentry.owner, None);
+        assert_eq!(ftpentry.group, None);
+        assert_eq!(ftpentry.size(), 1024);
+        assert_eq!(ftpentry.name(), "busybox.txt");
+    }
+
+    #[test]
+    fn single_numeric_ownership_column() {
+        let row = "drwxr-xr-x    2 1000         4096 Dec 21 10:13 dir";
+        let ftpentry = FtpEntryUnix::try_from(row).unwrap();
+
+        assert_eq!(ftpentry.kind(), FtpEntryKind::Directory);
+        assert_eq!(ftpentry.ownership.as_deref(), Some("1000"));
+        assert_eq!(ftpentry.owner, None);
+        assert_eq!(ftpentry.group, None);
+        assert_eq!(ftpentry.size(), 4096);
+    }
+
+    #[test]
+    fn capitalized_owner_and_group() {
+        let row = "-rw-r--r-- 1 Administrator Users 1024 Jan 1 2020 x";
+        let ftpentry = FtpEntryUnix::try_from(row).unwrap();
+
+        assert_eq!(ftpentry.owner.as_deref(), Some("Administrator"));
+        assert_eq!(ftpentry.group.as_deref(), Some("Users"));
+        assert_eq!(ftpentry.ownership, None);
+        assert_eq!(ftpentry.size(), 1024);
+        assert_eq!(ftpentry.name(), "x");
+    }
+
+    #[test]
+    fn human_readable_size() {
+        let row = "-rw-r--r--    1 admin    admin        4.2M Jan  1  2020 backup.tar";
+
+        let ftpentry = FtpEntry::try_from(row);
+        assert!(ftpentry.is_ok());
+        let ftpentry = ftpentry.unwrap();
+
+        assert_eq!(ftpentry.kind(), FtpEntryKind::File);
+        assert_eq!(ftpentry.name(), "backup.tar");
+        assert_eq!(ftpentry.size(), 4_404_019);
+        assert_eq!(ftpentry.is_unix_type(), true);
+
+        let ftpentry_unix = ftpentry.to_unix_type();
+        assert_eq!(ftpentry_unix.approximate_size, true);
+        assert_eq!(ftpentry_unix.owner.as_deref(), Some("admin"));
+        assert_eq!(ftpentry_unix.group.as_deref(), Some("admin"));
+        assert_eq!(ftpentry_unix.pointer, None);
+    }
+
+    #[test]
+    fn human_readable_size_units() {
+        let sizes = [
+            ("512", 512, false),
+            ("12K", 12 * 1024, true),
+            ("12k", 12_000, true),
+            ("1,5G", 1_610_612_736, true),
+            ("1.5GiB", 1_610_612_736, true),
+            ("1.5GB", 1_500_000_000, true),
+            ("2T", 2 * 1024 * 1024 * 1024 * 1024, true),
+        ];
+
+        for (size, expected, approximate) in sizes.iter() {
+            let row = format!(
+                "-rw-r--r--    1 admin    admin    {} Jan  1  2020 backup.tar",
+                size
+            );
+            let ftpentry = FtpEntryUnix::try_from(row.as_str()).unwrap();
+
+            assert_eq!(ftpentry.size(), *expected, "{}", size);
+            assert_eq!(ftpentry.approximate_size, *approximate, "{}", size);
+        }
+    }
+
+    #[test]
+    fn file_larger_than_4_gib() {
+        let row = "-rw-r--r--    1 admin    admin    5368709120 Jan  1  2020 backup.tar";
+
+        let ftpentry = FtpEntry::try_from(row).unwrap();
+        assert_eq!(ftpentry.size(), 5_368_709_120);
+    }
+
+    #[test]
+    fn error_size_overflow() {
+        let row = "-rw-r--r--    1 admin    admin    18446744073709551616 Jan  1  2020 backup.tar";
+
+        let err = FtpEntryUnix::try_from(row).unwrap_err();
+        assert_eq!(err.format(), Some(FtpEntryFormat::Unix));
+        assert_eq!(err.field(), FtpEntryField::Size);
+        assert_eq!(err.offset(), 34);
+
+        let row = "-rw-r--r--    1 admin    admin    16E Jan  1  2020 backup.tar";
+
+        let err = FtpEntryUnix::try_from(row).unwrap_err();
+        assert_eq!(err.field(), FtpEntryField::Size);
+        assert_eq!(err.offset(), 34);
+    }
+
+    #[test]
+    fn error_invalid_permissions() {
+        let row = "drwxr-xr-q  10 root   root    4096 Dec 21  2012 usr";
+
+        let err = FtpEntryUnix::try_from(row).unwrap_err();
+        assert_eq!(err.format(), Some(FtpEntryFormat::Unix));
+        assert_eq!(err.field(), FtpEntryField::Permissions);
+        assert_eq!(err.offset(), 1);
+    }
+
+    #[test]
+    fn permissions_bits_and_mode() {
+        let row = "drwxr-sr-t   2 owner   group    4096 Sep 02  2012 shared";
+        let ftpentry = FtpEntryUnix::try_from(row).unwrap();
+        let permissions = &ftpentry.permissions;
+
+        assert_eq!(
+            permissions.user(),
+            FtpEntryPermissionBits {
+                read: true,
+                write: true,
+                execute: true,
+            }
+        );
+        assert_eq!(
+            permissions.group(),
+            FtpEntryPermissionBits {
+                read: true,
+                write: false,
+                execute: true,
+            }
+        );
+        assert_eq!(permissions.setuid(), false);
+        assert_eq!(permissions.setgid(), true);
+        assert_eq!(permissions.sticky(), true);
+        assert_eq!(permissions.mode(), 0o3755);
+        assert_eq!(permissions.as_str(), "rwxr-sr-x");
+        assert_eq!(permissions.to_string(), "rwxr-sr-t");
+    }
+
+    #[test]
+    fn permissions_round_trip() {
+        for (string, mode) in [
+            ("rwxrwxrwt", 0o1777),
+            ("rwxrwx--T", 0o1770),
+            ("rwsr-xr-x", 0o4755),
+            ("rwSr-Sr--", 0o6644),
+            ("rw-r-----", 0o640),
+            ("---------", 0),
+        ] {
+            let permissions = FtpEntryPermissions::try_from(string).unwrap();
+            assert_eq!(permissions.mode(), mode);
+            assert_eq!(permissions.to_string(), string);
+
+            let permissions = FtpEntryPermissions::from_mode(mode);
+            assert_eq!(permissions.to_string(), string);
+        }
+
+        let permissions = FtpEntryPermissions::from_mode(0o100644);
+        assert_eq!(permissions.mode(), 0o644);
+        assert_eq!(permissions.as_str(), "rw-r--r--");
+    }
+
+    #[test]
+    fn error_invalid_permissions_string() {
+        let err = FtpEntryPermissions::try_from("rwxr-tr-x").unwrap_err();
+        assert_eq!(err.format(), None);
+        assert_eq!(err.field(), FtpEntryField::Permissions);
+        assert_eq!(err.offset(), 5);
+
+        let err = FtpEntryPermissions::try_from("rwx").unwrap_err();
+        assert_eq!(err.offset(), 3);
+    }
+
+    #[test]
+    fn error_invalid_size() {
+        let row = "-rw-rw-rw-   1 owner   group    70Q Sep 02  2012 music.mp3";
+
+        let err = FtpEntryUnix::try_from(row).unwrap_err();
+        assert_eq!(err.field(), FtpEntryField::Size);
+        assert_eq!(err.offset(), 32);
+    }
+
+    #[test]
+    fn error_missing_name() {
+        let row = "-rw-rw-rw-   1 owner   group    7045120 Sep 02  2012";
+
+        let err = FtpEntry::try_from(row).unwrap_err();
+        assert_eq!(err.format(), Some(FtpEntryFormat::Unix));
+        assert_eq!(err.field(), FtpEntryField::Name);
+        assert_eq!(err.offset(), row.len());
+    }
+
+    #[test]
+    fn error_invalid_kind() {
+        let err = FtpEntryKind::try_from("dir").unwrap_err();
+        assert_eq!(err.format(), None);
+        assert_eq!(err.field(), FtpEntryField::Kind);
+        assert_eq!(err.to_string(), "invalid kind at byte 0");
+    }
 }
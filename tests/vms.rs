@@ -0,0 +1,134 @@
+#![allow(clippy::bool_assert_comparison)]
+
+mod vms {
+    use std::convert::TryFrom;
+
+    use ::ftp_cmd_list_parse::*;
+
+    #[test]
+    fn normal_file() {
+        let row = "FILE.TXT;3   12/24   1-JAN-2020 10:00:00  [GROUP,OWNER]  (RWED,RWED,RE,)";
+
+        let ftpentry = FtpEntry::try_from(row);
+        assert!(ftpentry.is_ok());
+        let ftpentry = ftpentry.unwrap();
+
+        assert_eq!(ftpentry.kind(), FtpEntryKind::File);
+        assert_eq!(ftpentry.name(), "FILE.TXT");
+        assert_eq!(ftpentry.size(), 12 * 512);
+        assert_eq!(ftpentry.date_str(), "1-JAN-2020 10:00:00");
+        assert_eq!(
+            ftpentry.date(),
+            FtpEntryDate::from_ymd_hms(2020, 1, 1, 10, 0, 0)
+        );
+        assert_eq!(ftpentry.is_vms_type(), true);
+        assert_eq!(ftpentry.is_unix_type(), false);
+
+        let ftpentry_vms = ftpentry.to_vms_type();
+
+        assert_eq!(ftpentry_vms.version, 3);
+        assert_eq!(ftpentry_vms.blocks_used, 12);
+        assert_eq!(ftpentry_vms.blocks_allocated, Some(24));
+        assert_eq!(
+            ftpentry_vms.owner,
+            FtpEntryVmsUic {
+                group: Some("GROUP".to_string()),
+                member: "OWNER".to_string(),
+            }
+        );
+        assert_eq!(
+            ftpentry_vms.protection,
+            FtpEntryVmsProtection {
+                system: FtpEntryVmsAccess {
+                    read: true,
+                    write: true,
+                    execute: true,
+                    delete: true,
+                },
+                owner: FtpEntryVmsAccess {
+                    read: true,
+                    write: true,
+                    execute: true,
+                    delete: true,
+                },
+                group: FtpEntryVmsAccess {
+                    read: true,
+                    execute: true,
+                    ..Default::default()
+                },
+                world: FtpEntryVmsAccess::default(),
+            }
+        );
+    }
+
+    #[test]
+    fn directory_without_allocated_blocks() {
+        let row = "SUBDIR.DIR;1          1  23-SEP-2019 08:15  [SYSTEM]  (RWE,RWE,RE,RE)";
+
+        let ftpentry = FtpEntryVms::try_from(row).unwrap();
+
+        assert_eq!(ftpentry.kind(), FtpEntryKind::Directory);
+        assert_eq!(ftpentry.name(), "SUBDIR.DIR");
+        assert_eq!(
+            ftpentry.date(),
+            FtpEntryDate::from_ymd_hm(2019, 9, 23, 8, 15)
+        );
+        assert_eq!(ftpentry.version, 1);
+        assert_eq!(ftpentry.blocks_allocated, None);
+        assert_eq!(
+            ftpentry.owner,
+            FtpEntryVmsUic {
+                group: None,
+                member: "SYSTEM".to_string(),
+            }
+        );
+        assert_eq!(ftpentry.protection.world.read, true);
+        assert_eq!(ftpentry.protection.world.delete, false);
+    }
+
+    #[test]
+    fn timestamp_with_hundredths() {
+        let row = "LOGIN.COM;12  3/4  14-FEB-2021 23:59:58.12 [100,20] (RWED,RWED,R,R)";
+
+        let ftpentry = FtpEntryVms::try_from(row).unwrap();
+
+        assert_eq!(
+            ftpentry.date(),
+            FtpEntryDate::from_ymd_hms(2021, 2, 14, 23, 59, 58)
+        );
+        assert_eq!(ftpentry.owner.group, Some("100".to_string()));
+        assert_eq!(ftpentry.owner.member, "20");
+    }
+
+    #[test]
+    fn listing_with_header_and_wrapped_name() {
+        let listing = "Directory DISK$USER:[GROUP.OWNER]\r\n\
+            \r\n\
+            A_VERY_LONG_FILE_NAME_THAT_WRAPS.TXT;1\r\n\
+            \x20                   5/6   1-JAN-2020 10:00:00  [GROUP,OWNER]  (RWED,RWED,RE,)\r\n\
+            FILE.TXT;3   12/24   1-JAN-2020 10:00:00  [GROUP,OWNER]  (RWED,RWED,RE,)\r\n\
+            \r\n\
+            Total of 2 files, 17/30 blocks.\r\n";
+
+        let listing = FtpListing::parse(listing);
+
+        assert_eq!(listing.unparsed, vec![]);
+        assert_eq!(listing.entries.len(), 2);
+        assert_eq!(
+            listing.entries[0].name(),
+            "A_VERY_LONG_FILE_NAME_THAT_WRAPS.TXT"
+        );
+        assert_eq!(listing.entries[0].size(), 5 * 512);
+        assert_eq!(listing.entries[1].name(), "FILE.TXT");
+    }
+
+    #[test]
+    fn error_invalid_protection() {
+        let row = "FILE.TXT;3   12/24   1-JAN-2020 10:00:00  [GROUP,OWNER]  (RWXD,RWED,RE,)";
+
+        let err = FtpEntry::try_from(row).unwrap_err();
+        assert_eq!(err.format(), Some(FtpEntryFormat::Vms));
+        assert_eq!(err.field(), FtpEntryField::Permissions);
+        assert_eq!(err.offset(), 57);
+    }
+}